@@ -0,0 +1,44 @@
+// ######################################################################
+// # sparkplug_b code generation
+// ######################################################################
+// Generates the `sparkplug_b` protobuf module from `sparkplug_b.proto` into
+// OUT_DIR, where `lib.rs` includes it. The proto is parsed in pure Rust so no
+// `protoc` has to be installed; the `protoc` feature uses the system `protoc`
+// instead.
+// ######################################################################
+
+const PROTO: &str = "sparkplug_b.proto";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", PROTO);
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    generate(&out_dir);
+}
+
+#[cfg(not(feature = "protoc"))]
+fn generate(out_dir: &str) {
+    protobuf_codegen_pure::Codegen::new()
+        .out_dir(out_dir)
+        .inputs([PROTO])
+        .include(".")
+        .customize(protobuf_codegen_pure::Customize {
+            gen_mod_rs: Some(true),
+            ..Default::default()
+        })
+        .run()
+        .expect("generating sparkplug_b from sparkplug_b.proto failed");
+}
+
+#[cfg(feature = "protoc")]
+fn generate(out_dir: &str) {
+    protoc_rust::Codegen::new()
+        .out_dir(out_dir)
+        .inputs([PROTO])
+        .include(".")
+        .customize(protoc_rust::Customize {
+            gen_mod_rs: Some(true),
+            ..Default::default()
+        })
+        .run()
+        .expect("running protoc on sparkplug_b.proto failed");
+}
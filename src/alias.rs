@@ -0,0 +1,218 @@
+use crate::decode::Metric;
+use crate::sparkplug_b::Payload_Metric;
+use crate::SparkplugError;
+use std::collections::HashMap;
+
+// ######################################################################
+// # Metric aliases
+// ######################################################################
+// A birth declares each metric with both its name and an alias, DATA
+// messages may then send the alias alone. Aliases must be unique across an
+// edge node and all of its devices, so one registry covers the node
+// (`device_id` None) and every device under it.
+// ######################################################################
+
+#[derive(Debug, Default, Clone)]
+pub struct AliasRegistry {
+    aliases: HashMap<(Option<String>, String), u64>,
+    names: HashMap<u64, (Option<String>, String)>,
+    next: u64,
+}
+
+impl AliasRegistry {
+    pub fn new() -> AliasRegistry {
+        AliasRegistry::default()
+    }
+
+    pub fn clear(&mut self) {
+        self.aliases.clear();
+        self.names.clear();
+        self.next = 0;
+    }
+
+    // Forgets the aliases of one device, e.g. before its DBIRTH is replaced.
+    pub fn clear_device(&mut self, device_id: &str) {
+        self.aliases
+            .retain(|(d, _), _| d.as_deref() != Some(device_id));
+        self.names
+            .retain(|_, (d, _)| d.as_deref() != Some(device_id));
+    }
+
+    pub fn alias(&self, device_id: Option<&str>, name: &str) -> Option<u64> {
+        self.aliases
+            .get(&(device_id.map(String::from), name.to_string()))
+            .copied()
+    }
+
+    pub fn name(&self, alias: u64) -> Option<&str> {
+        self.names.get(&alias).map(|(_, name)| name.as_str())
+    }
+
+    // Edge side: records the aliases already set on the birth metrics and
+    // gives every other named metric the next free one.
+    pub fn assign(
+        &mut self,
+        device_id: Option<&str>,
+        metrics: &mut [Payload_Metric],
+    ) -> Result<(), SparkplugError> {
+        for metric in metrics.iter().filter(|m| m.has_name() && m.has_alias()) {
+            self.insert(device_id, metric.get_name(), metric.get_alias())?;
+        }
+        for metric in metrics
+            .iter_mut()
+            .filter(|m| m.has_name() && !m.has_alias())
+        {
+            let alias = match self.alias(device_id, metric.get_name()) {
+                Some(alias) => alias,
+                None => {
+                    let alias = self.next;
+                    self.insert(device_id, metric.get_name(), alias)?;
+                    alias
+                }
+            };
+            metric.set_alias(alias);
+        }
+        Ok(())
+    }
+
+    // Host side: records the aliases declared by a received birth.
+    pub fn register(
+        &mut self,
+        device_id: Option<&str>,
+        metrics: &[Metric],
+    ) -> Result<(), SparkplugError> {
+        for metric in metrics {
+            if let (Some(name), Some(alias)) = (&metric.name, metric.alias) {
+                self.insert(device_id, name, alias)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Edge side: replaces the name of each DATA metric with its alias.
+    pub fn compress(
+        &self,
+        device_id: Option<&str>,
+        metrics: &mut [Payload_Metric],
+    ) -> Result<(), SparkplugError> {
+        for metric in metrics.iter_mut().filter(|m| m.has_name()) {
+            let alias = self
+                .alias(device_id, metric.get_name())
+                .ok_or_else(|| SparkplugError::UnknownMetric(metric.get_name().into()))?;
+            metric.clear_name();
+            metric.set_alias(alias);
+        }
+        Ok(())
+    }
+
+    // Host side: fills in the name of each metric sent by alias only.
+    pub fn resolve(&self, device_id: Option<&str>, metrics: &mut [Metric]) {
+        for metric in metrics.iter_mut().filter(|m| m.name.is_none()) {
+            if let Some((d, name)) = metric.alias.and_then(|a| self.names.get(&a)) {
+                if d.as_deref() == device_id {
+                    metric.name = Some(name.clone());
+                }
+            }
+        }
+    }
+
+    fn insert(
+        &mut self,
+        device_id: Option<&str>,
+        name: &str,
+        alias: u64,
+    ) -> Result<(), SparkplugError> {
+        let key = (device_id.map(String::from), name.to_string());
+        match self.names.get(&alias) {
+            Some(owner) if *owner == key => return Ok(()),
+            Some(_) => {
+                return Err(SparkplugError::DuplicateAlias {
+                    alias,
+                    metric: name.into(),
+                })
+            }
+            None => (),
+        }
+        if let Some(previous) = self.aliases.insert(key.clone(), alias) {
+            self.names.remove(&previous);
+        }
+        self.names.insert(alias, key);
+        self.next = self.next.max(alias + 1);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::decode_metric;
+    use crate::{create_metric, FixedClock, MetricDataType};
+
+    fn metric(name: &str, alias: Option<u64>) -> Payload_Metric {
+        let clock = FixedClock::new(1);
+        create_metric(&clock, MetricDataType::Int32, 1, name.into(), alias, None).unwrap()
+    }
+
+    #[test]
+    fn assign_keeps_given_aliases_and_numbers_the_rest_after_them() {
+        let mut registry = AliasRegistry::new();
+        let mut node = vec![metric("a", None), metric("b", Some(5))];
+        registry.assign(None, &mut node).unwrap();
+        assert_eq!(node[0].get_alias(), 6);
+        assert_eq!(node[1].get_alias(), 5);
+
+        // Unique across the node and its devices, even for the same name.
+        let mut device = vec![metric("a", None)];
+        registry.assign(Some("D"), &mut device).unwrap();
+        assert_eq!(device[0].get_alias(), 7);
+
+        // A rebirth gets the same aliases back.
+        let mut rebirth = vec![metric("b", None), metric("a", None)];
+        registry.assign(None, &mut rebirth).unwrap();
+        assert_eq!(rebirth[0].get_alias(), 5);
+        assert_eq!(rebirth[1].get_alias(), 6);
+    }
+
+    #[test]
+    fn alias_of_another_metric_is_rejected() {
+        let mut registry = AliasRegistry::new();
+        registry.assign(None, &mut [metric("a", Some(1))]).unwrap();
+        assert!(matches!(
+            registry.assign(Some("D"), &mut [metric("b", Some(1))]),
+            Err(SparkplugError::DuplicateAlias { alias: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn compress_sends_the_alias_only() {
+        let mut registry = AliasRegistry::new();
+        registry.assign(None, &mut [metric("a", Some(3))]).unwrap();
+        let mut data = vec![metric("a", None)];
+        registry.compress(None, &mut data).unwrap();
+        assert!(!data[0].has_name());
+        assert_eq!(data[0].get_alias(), 3);
+
+        assert!(matches!(
+            registry.compress(Some("D"), &mut [metric("a", None)]),
+            Err(SparkplugError::UnknownMetric(_))
+        ));
+    }
+
+    #[test]
+    fn resolve_names_alias_only_metrics_of_the_same_device() {
+        let mut registry = AliasRegistry::new();
+        let birth = vec![decode_metric(&metric("a", Some(2))).unwrap()];
+        registry.register(Some("D"), &birth).unwrap();
+
+        let mut alias_only = metric("a", Some(2));
+        alias_only.clear_name();
+        let mut data = vec![decode_metric(&alias_only).unwrap()];
+        registry.resolve(None, &mut data);
+        assert_eq!(data[0].name, None);
+        registry.resolve(Some("D"), &mut data);
+        assert_eq!(data[0].name.as_deref(), Some("a"));
+
+        registry.clear_device("D");
+        assert_eq!(registry.name(2), None);
+    }
+}
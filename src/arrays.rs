@@ -0,0 +1,239 @@
+use crate::{MetricDataType, MetricValue};
+use chrono::{DateTime, TimeZone, Utc};
+
+// ######################################################################
+// # Array encoding
+// ######################################################################
+// Sparkplug 3.0 carries array metrics in `bytes_value`:
+//
+//   numeric arrays   each element packed little endian, no separator
+//   DateTimeArray    each element as an int64 of epoch milliseconds
+//   StringArray      each element UTF-8 encoded and null terminated
+//   BooleanArray     a uint32 LE element count, then the values packed
+//                    eight to a byte, most significant bit first
+// ######################################################################
+
+macro_rules! pack {
+    ($values:expr) => {
+        $values.iter().flat_map(|v| v.to_le_bytes()).collect()
+    };
+}
+
+macro_rules! unpack {
+    ($t:ty, $bytes:expr) => {{
+        const SIZE: usize = std::mem::size_of::<$t>();
+        if $bytes.len() % SIZE != 0 {
+            return None;
+        }
+        $bytes
+            .chunks_exact(SIZE)
+            .map(|c| {
+                let mut buf = [0u8; SIZE];
+                buf.copy_from_slice(c);
+                <$t>::from_le_bytes(buf)
+            })
+            .collect::<Vec<$t>>()
+    }};
+}
+
+// The packed bytes of an array value, `None` for every other value and for a
+// StringArray with a null byte inside an element, which would split it.
+pub fn encode(value: &MetricValue) -> Option<Vec<u8>> {
+    let bytes = match value {
+        MetricValue::Int8Array(v) => pack!(v),
+        MetricValue::Int16Array(v) => pack!(v),
+        MetricValue::Int32Array(v) => pack!(v),
+        MetricValue::Int64Array(v) => pack!(v),
+        MetricValue::UInt8Array(v) => v.clone(),
+        MetricValue::UInt16Array(v) => pack!(v),
+        MetricValue::UInt32Array(v) => pack!(v),
+        MetricValue::UInt64Array(v) => pack!(v),
+        MetricValue::FloatArray(v) => pack!(v),
+        MetricValue::DoubleArray(v) => pack!(v),
+        MetricValue::DateTimeArray(v) => v
+            .iter()
+            .flat_map(|d| d.timestamp_millis().to_le_bytes())
+            .collect(),
+        MetricValue::StringArray(v) => {
+            let mut bytes = vec![];
+            for s in v {
+                if s.contains('\0') {
+                    return None;
+                }
+                bytes.extend_from_slice(s.as_bytes());
+                bytes.push(0);
+            }
+            bytes
+        }
+        MetricValue::BooleanArray(v) => {
+            let mut bytes = (v.len() as u32).to_le_bytes().to_vec();
+            for chunk in v.chunks(8) {
+                let mut byte = 0u8;
+                for (i, b) in chunk.iter().enumerate() {
+                    if *b {
+                        byte |= 0x80 >> i;
+                    }
+                }
+                bytes.push(byte);
+            }
+            bytes
+        }
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+// Unpacks `bytes` as `data_type`. `None` when `data_type` is not an array
+// type or the bytes are not a valid packing of it.
+pub fn decode(data_type: MetricDataType, bytes: &[u8]) -> Option<MetricValue> {
+    let value = match data_type {
+        MetricDataType::Int8Array => {
+            MetricValue::Int8Array(bytes.iter().map(|b| *b as i8).collect())
+        }
+        MetricDataType::Int16Array => MetricValue::Int16Array(unpack!(i16, bytes)),
+        MetricDataType::Int32Array => MetricValue::Int32Array(unpack!(i32, bytes)),
+        MetricDataType::Int64Array => MetricValue::Int64Array(unpack!(i64, bytes)),
+        MetricDataType::UInt8Array => MetricValue::UInt8Array(bytes.to_vec()),
+        MetricDataType::UInt16Array => MetricValue::UInt16Array(unpack!(u16, bytes)),
+        MetricDataType::UInt32Array => MetricValue::UInt32Array(unpack!(u32, bytes)),
+        MetricDataType::UInt64Array => MetricValue::UInt64Array(unpack!(u64, bytes)),
+        MetricDataType::FloatArray => MetricValue::FloatArray(unpack!(f32, bytes)),
+        MetricDataType::DoubleArray => MetricValue::DoubleArray(unpack!(f64, bytes)),
+        MetricDataType::DateTimeArray => MetricValue::DateTimeArray(
+            unpack!(i64, bytes)
+                .into_iter()
+                .map(|ms| Utc.timestamp_millis_opt(ms).single())
+                .collect::<Option<Vec<DateTime<Utc>>>>()?,
+        ),
+        MetricDataType::StringArray => {
+            // Every element, the last one included, ends with a null byte.
+            let strings = match bytes.split_last() {
+                None => vec![],
+                Some((0, body)) => body
+                    .split(|b| *b == 0)
+                    .map(|s| String::from_utf8(s.to_vec()).ok())
+                    .collect::<Option<Vec<String>>>()?,
+                Some(_) => return None,
+            };
+            MetricValue::StringArray(strings)
+        }
+        MetricDataType::BooleanArray => {
+            if bytes.len() < 4 {
+                return None;
+            }
+            let (count, packed) = bytes.split_at(4);
+            let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize;
+            if packed.len() != count.div_ceil(8) {
+                return None;
+            }
+            MetricValue::BooleanArray(
+                (0..count)
+                    .map(|i| packed[i / 8] & (0x80 >> (i % 8)) != 0)
+                    .collect(),
+            )
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: MetricValue) -> Vec<u8> {
+        let bytes = encode(&value).unwrap();
+        assert_eq!(decode(value.data_type(), &bytes), Some(value));
+        bytes
+    }
+
+    #[test]
+    fn integer_arrays_are_packed_little_endian() {
+        assert_eq!(
+            round_trip(MetricValue::Int8Array(vec![-23, 123])),
+            [0xe9, 0x7b]
+        );
+        assert_eq!(
+            round_trip(MetricValue::Int16Array(vec![-30000, 30000])),
+            [0xd0, 0x8a, 0x30, 0x75]
+        );
+        assert_eq!(
+            round_trip(MetricValue::Int32Array(vec![-1, 1])),
+            [0xff, 0xff, 0xff, 0xff, 0x01, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            round_trip(MetricValue::Int64Array(vec![-2])),
+            [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            round_trip(MetricValue::UInt8Array(vec![23, 250])),
+            [23, 250]
+        );
+        assert_eq!(
+            round_trip(MetricValue::UInt16Array(vec![30, 52360])),
+            [0x1e, 0x00, 0x88, 0xcc]
+        );
+        assert_eq!(
+            round_trip(MetricValue::UInt32Array(vec![52, 3293969225])),
+            [0x34, 0x00, 0x00, 0x00, 0x49, 0xfb, 0x55, 0xc4]
+        );
+        assert_eq!(
+            round_trip(MetricValue::UInt64Array(vec![u64::MAX])),
+            [0xff; 8]
+        );
+    }
+
+    #[test]
+    fn float_arrays_are_packed_little_endian() {
+        assert_eq!(
+            round_trip(MetricValue::FloatArray(vec![1.23, 89.341])),
+            [0xa4, 0x70, 0x9d, 0x3f, 0x98, 0xae, 0xb2, 0x42]
+        );
+        assert_eq!(
+            round_trip(MetricValue::DoubleArray(vec![-1.0])),
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0xbf]
+        );
+    }
+
+    #[test]
+    fn datetime_array_is_packed_as_epoch_millis() {
+        let date = Utc.timestamp_millis_opt(1256102875335).unwrap();
+        assert_eq!(
+            round_trip(MetricValue::DateTimeArray(vec![date])),
+            1256102875335i64.to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn string_array_is_null_terminated() {
+        assert_eq!(
+            round_trip(MetricValue::StringArray(vec!["ab".into(), "".into()])),
+            b"ab\0\0"
+        );
+        assert_eq!(round_trip(MetricValue::StringArray(vec![])), b"");
+        assert_eq!(decode(MetricDataType::StringArray, b"ab"), None);
+    }
+
+    #[test]
+    fn string_with_null_byte_is_rejected() {
+        assert_eq!(encode(&MetricValue::StringArray(vec!["a\0b".into()])), None);
+    }
+
+    #[test]
+    fn boolean_array_has_count_and_msb_first_bits() {
+        let values = vec![false, false, true, true, false, true, false, false, true];
+        assert_eq!(
+            round_trip(MetricValue::BooleanArray(values)),
+            [0x09, 0x00, 0x00, 0x00, 0b0011_0100, 0b1000_0000]
+        );
+        assert_eq!(round_trip(MetricValue::BooleanArray(vec![])), [0, 0, 0, 0]);
+        assert_eq!(decode(MetricDataType::BooleanArray, &[2, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn truncated_elements_are_rejected() {
+        assert_eq!(decode(MetricDataType::Int32Array, &[1, 2, 3]), None);
+        assert_eq!(decode(MetricDataType::Int32, &[1, 2, 3, 4]), None);
+        assert_eq!(encode(&MetricValue::Int32(1)), None);
+    }
+}
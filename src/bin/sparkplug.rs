@@ -0,0 +1,422 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::SecondsFormat;
+use protobuf::Message as _;
+use spark_rust::dataset::DataSetReader;
+use spark_rust::decode::{decode, decode_metric, Metric};
+use spark_rust::json::{from_json_str, to_json_string};
+use spark_rust::parse::parse_bytes;
+use spark_rust::properties::{PropertySet, PropertyValue};
+use spark_rust::sparkplug_b::{Payload, Payload_Template};
+use spark_rust::state::decode_state;
+use spark_rust::template::read_parameter;
+use spark_rust::validate::validate;
+use spark_rust::{MessageType, MetricValue, Topic};
+use std::io::{Read, Write};
+use std::process::ExitCode;
+
+// ######################################################################
+// # sparkplug command-line tool
+// ######################################################################
+// Decodes, encodes and inspects Sparkplug B payloads captured from MQTT
+// traffic, without a broker.
+// ######################################################################
+
+const USAGE: &str = "\
+usage: sparkplug <command> [options]
+
+commands:
+  decode <topic> [file]     print the payload as text, or as JSON with --json
+  encode [file]             read payload JSON and write the protobuf encoding
+  validate <topic> [file]   check the payload against the Sparkplug B rules
+  size [file]               show the encoded size of each metric
+
+Payloads are read from the file, or from stdin without one or with `-`.
+
+options:
+  --input raw|hex|base64    how the payload is given, by default hex when
+                            0x-prefixed or only an even number of hex digits,
+                            else base64, else raw
+  --output raw|hex|base64   how encode writes the payload, raw by default,
+                            hex is written 0x-prefixed
+  --json                    decode to the JSON form that encode reads
+";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Encoding {
+    Auto,
+    Raw,
+    Hex,
+    Base64,
+}
+
+struct Args {
+    command: String,
+    positional: Vec<String>,
+    input: Encoding,
+    output: Encoding,
+    json: bool,
+}
+
+fn main() -> ExitCode {
+    if std::env::args().skip(1).any(|a| a == "-h" || a == "--help") {
+        print!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("sparkplug: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(&args) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("sparkplug: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(mut raw: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = Args {
+        command: raw.next().ok_or("no command given")?,
+        positional: vec![],
+        input: Encoding::Auto,
+        output: Encoding::Raw,
+        json: false,
+    };
+    while let Some(arg) = raw.next() {
+        match arg.as_str() {
+            "--input" => args.input = encoding(raw.next())?,
+            "--output" => args.output = encoding(raw.next())?,
+            "--json" => args.json = true,
+            a if a.starts_with("--") => return Err(format!("unknown option `{}`", a)),
+            _ => args.positional.push(arg),
+        }
+    }
+    if !["decode", "encode", "validate", "size"].contains(&args.command.as_str()) {
+        return Err(format!("unknown command `{}`", args.command));
+    }
+    if args.output == Encoding::Auto {
+        return Err("--output cannot be auto".into());
+    }
+    Ok(args)
+}
+
+fn encoding(value: Option<String>) -> Result<Encoding, String> {
+    match value.as_deref() {
+        Some("auto") => Ok(Encoding::Auto),
+        Some("raw") => Ok(Encoding::Raw),
+        Some("hex") => Ok(Encoding::Hex),
+        Some("base64") => Ok(Encoding::Base64),
+        Some(v) => Err(format!("unknown encoding `{}`", v)),
+        None => Err("missing encoding".into()),
+    }
+}
+
+fn run(args: &Args) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let (topic, file) = match args.command.as_str() {
+        "decode" | "validate" => {
+            let topic = args.positional.first().ok_or("missing topic")?;
+            (Some(topic.parse::<Topic>()?), args.positional.get(1))
+        }
+        _ => (None, args.positional.first()),
+    };
+    let input = read_input(file)?;
+
+    match (args.command.as_str(), topic) {
+        ("encode", _) => {
+            let payload = from_json_str(std::str::from_utf8(&input)?)?;
+            write_output(&payload.write_to_bytes()?, args.output)?;
+        }
+        ("size", _) => {
+            let payload = Payload::parse_from_bytes(&decode_input(&input, args.input)?)?;
+            print_sizes(&payload);
+        }
+        ("decode", Some(topic)) if topic.message_type() == MessageType::STATE => {
+            let (host_id, state) = decode_state(&topic, &decode_input(&input, args.input)?)?;
+            let status = if state.online { "online" } else { "offline" };
+            match state.timestamp {
+                Some(t) => println!("STATE {} {} timestamp {}", host_id, status, t),
+                None => println!("STATE {} {}", host_id, status),
+            }
+        }
+        ("decode", Some(topic)) => {
+            let bytes = decode_input(&input, args.input)?;
+            if args.json {
+                println!("{}", to_json_string(&Payload::parse_from_bytes(&bytes)?)?);
+            } else {
+                print_message(&topic, &bytes)?;
+            }
+        }
+        ("validate", Some(topic)) => {
+            let payload = Payload::parse_from_bytes(&decode_input(&input, args.input)?)?;
+            let problems = validate(&topic, &payload);
+            if problems.is_empty() {
+                println!("valid {:?}", topic.message_type());
+                return Ok(ExitCode::SUCCESS);
+            }
+            for problem in problems {
+                println!("{}", problem);
+            }
+            return Ok(ExitCode::FAILURE);
+        }
+        _ => unreachable!(),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read_input(file: Option<&String>) -> std::io::Result<Vec<u8>> {
+    match file.map(String::as_str) {
+        None | Some("-") => {
+            let mut input = vec![];
+            std::io::stdin().read_to_end(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read(path),
+    }
+}
+
+// Hex and base64 may be spread over several lines, e.g. from a hex dump.
+fn decode_input(input: &[u8], encoding: Encoding) -> Result<Vec<u8>, String> {
+    let text = || -> Result<String, String> {
+        let text = std::str::from_utf8(input).map_err(|_| "input is not text")?;
+        Ok(text.split_whitespace().collect())
+    };
+    match encoding {
+        Encoding::Raw => Ok(input.to_vec()),
+        Encoding::Hex => {
+            let text = text()?;
+            let hex = text.strip_prefix("0x").unwrap_or(&text);
+            if hex.len() % 2 != 0 {
+                return Err("odd number of hex digits".into());
+            }
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+                .collect::<Result<Vec<u8>, _>>()
+                .map_err(|e| format!("invalid hex: {}", e))
+        }
+        Encoding::Base64 => STANDARD
+            .decode(text()?)
+            .map_err(|e| format!("invalid base64: {}", e)),
+        // A protobuf payload is hardly ever valid text, let alone hex or base64.
+        Encoding::Auto => Ok(text()
+            .ok()
+            .filter(|t| !t.is_empty())
+            .and_then(|t| parse_bytes(&t))
+            .unwrap_or_else(|| input.to_vec())),
+    }
+}
+
+fn write_output(bytes: &[u8], encoding: Encoding) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    match encoding {
+        Encoding::Hex => writeln!(stdout, "0x{}", hex(bytes)),
+        Encoding::Base64 => writeln!(stdout, "{}", STANDARD.encode(bytes)),
+        _ => stdout.write_all(bytes),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn print_message(topic: &Topic, bytes: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let message = decode(topic.clone(), bytes)?;
+    println!("{:?} {}", topic.message_type(), topic);
+    if let Some(t) = message.timestamp {
+        println!("timestamp {} ({})", t, datetime(t));
+    }
+    if let Some(seq) = message.seq {
+        println!("seq {}", seq);
+    }
+    if let Some(uuid) = &message.uuid {
+        println!("uuid {}", uuid);
+    }
+    if let Some(body) = &message.body {
+        println!("body {} bytes", body.len());
+    }
+    for metric in &message.metrics {
+        print_metric(metric, 1)?;
+    }
+    Ok(())
+}
+
+fn print_metric(metric: &Metric, depth: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let indent = "  ".repeat(depth);
+    let mut line = format!(
+        "{}{}",
+        indent,
+        metric.name.as_deref().unwrap_or("<unnamed>")
+    );
+    if let Some(alias) = metric.alias {
+        line += &format!(" [alias {}]", alias);
+    }
+    line += &format!(" {:?}", metric.data_type);
+    if metric.is_historical {
+        line += " historical";
+    }
+    if metric.is_transient {
+        line += " transient";
+    }
+    match &metric.value {
+        None => println!("{} = null", line),
+        Some(MetricValue::DataSet(dataset)) => {
+            println!("{}", line);
+            let reader = DataSetReader::new(dataset)?;
+            let columns = reader
+                .columns()
+                .iter()
+                .zip(reader.types())
+                .map(|(c, t)| format!("{} {:?}", c, t));
+            println!(
+                "{}  | {} |",
+                indent,
+                columns.collect::<Vec<_>>().join(" | ")
+            );
+            for row in reader.rows() {
+                let cells = row
+                    .iter()
+                    .map(|c| c.as_ref().map_or("null".into(), value_text));
+                println!("{}  | {} |", indent, cells.collect::<Vec<_>>().join(" | "));
+            }
+        }
+        Some(MetricValue::Template(template)) => {
+            println!("{}{}", line, template_text(template));
+            for parameter in template.get_parameters() {
+                let parameter = read_parameter(parameter)?;
+                let value = parameter.value.as_ref().map_or("null".into(), value_text);
+                println!(
+                    "{}  parameter {} {:?} = {}",
+                    indent, parameter.name, parameter.data_type, value
+                );
+            }
+            for member in template.get_metrics() {
+                print_metric(&decode_metric(member)?, depth + 1)?;
+            }
+        }
+        Some(value) => println!("{} = {}", line, value_text(value)),
+    }
+    if let Some(properties) = &metric.properties {
+        print_properties(properties, depth + 1);
+    }
+    Ok(())
+}
+
+fn template_text(template: &Payload_Template) -> String {
+    let mut text = String::new();
+    if template.get_is_definition() {
+        text += " definition";
+    }
+    if template.has_template_ref() {
+        text += &format!(" of {}", template.get_template_ref());
+    }
+    if template.has_version() {
+        text += &format!(" version {}", template.get_version());
+    }
+    text
+}
+
+fn print_properties(properties: &PropertySet, depth: usize) {
+    let indent = "  ".repeat(depth);
+    for (key, value) in properties.iter() {
+        match value {
+            PropertyValue::Value(v) => {
+                println!("{}@{} {:?} = {}", indent, key, v.data_type(), value_text(v))
+            }
+            PropertyValue::Null(t) => println!("{}@{} {:?} = null", indent, key, t),
+            PropertyValue::PropertySet(set) => {
+                println!("{}@{} PropertySet", indent, key);
+                print_properties(set, depth + 1);
+            }
+            PropertyValue::PropertySetList(list) => {
+                println!("{}@{} PropertySetList", indent, key);
+                for (i, set) in list.iter().enumerate() {
+                    println!("{}  [{}]", indent, i);
+                    print_properties(set, depth + 2);
+                }
+            }
+        }
+    }
+}
+
+fn value_text(value: &MetricValue) -> String {
+    let rfc3339 =
+        |d: &chrono::DateTime<chrono::Utc>| d.to_rfc3339_opts(SecondsFormat::Millis, true);
+    match value {
+        MetricValue::String(v) | MetricValue::Text(v) | MetricValue::UUID(v) => {
+            format!("{:?}", v)
+        }
+        MetricValue::DateTime(v) => rfc3339(v),
+        MetricValue::Bytes(v) | MetricValue::File(v) => {
+            format!("{} bytes 0x{}", v.len(), hex(v))
+        }
+        MetricValue::Int8(v) => v.to_string(),
+        MetricValue::Int16(v) => v.to_string(),
+        MetricValue::Int32(v) => v.to_string(),
+        MetricValue::Int64(v) => v.to_string(),
+        MetricValue::UInt8(v) => v.to_string(),
+        MetricValue::UInt16(v) => v.to_string(),
+        MetricValue::UInt32(v) => v.to_string(),
+        MetricValue::UInt64(v) => v.to_string(),
+        MetricValue::Float(v) => v.to_string(),
+        MetricValue::Double(v) => v.to_string(),
+        MetricValue::Boolean(v) => v.to_string(),
+        // Printed row by row and member by member in `print_metric`.
+        MetricValue::DataSet(_) => "DataSet".into(),
+        MetricValue::Template(_) => "Template".into(),
+        MetricValue::Int8Array(v) => list(v.iter().map(i8::to_string)),
+        MetricValue::Int16Array(v) => list(v.iter().map(i16::to_string)),
+        MetricValue::Int32Array(v) => list(v.iter().map(i32::to_string)),
+        MetricValue::Int64Array(v) => list(v.iter().map(i64::to_string)),
+        MetricValue::UInt8Array(v) => list(v.iter().map(u8::to_string)),
+        MetricValue::UInt16Array(v) => list(v.iter().map(u16::to_string)),
+        MetricValue::UInt32Array(v) => list(v.iter().map(u32::to_string)),
+        MetricValue::UInt64Array(v) => list(v.iter().map(u64::to_string)),
+        MetricValue::FloatArray(v) => list(v.iter().map(f32::to_string)),
+        MetricValue::DoubleArray(v) => list(v.iter().map(f64::to_string)),
+        MetricValue::BooleanArray(v) => list(v.iter().map(bool::to_string)),
+        MetricValue::StringArray(v) => list(v.iter().map(|s| format!("{:?}", s))),
+        MetricValue::DateTimeArray(v) => list(v.iter().map(rfc3339)),
+    }
+}
+
+fn list(items: impl Iterator<Item = String>) -> String {
+    format!("[{}]", items.collect::<Vec<_>>().join(", "))
+}
+
+fn datetime(millis: u64) -> String {
+    chrono::DateTime::from_timestamp_millis(millis as i64).map_or("out of range".into(), |d| {
+        d.to_rfc3339_opts(SecondsFormat::Millis, true)
+    })
+}
+
+// The bytes each metric takes in the payload, with its field tag and length.
+fn print_sizes(payload: &Payload) {
+    let total = payload.compute_size() as usize;
+    let percent = |n: usize| 100.0 * n as f64 / total.max(1) as f64;
+    println!("{:>8} {:>6}  metric", "bytes", "%");
+    let mut metrics = 0;
+    for metric in payload.get_metrics() {
+        let size = metric.compute_size();
+        let size = 1 + protobuf::rt::compute_raw_varint32_size(size) as usize + size as usize;
+        metrics += size;
+        let label = match (metric.has_name(), metric.has_alias()) {
+            (true, true) => format!("{} [alias {}]", metric.get_name(), metric.get_alias()),
+            (true, false) => metric.get_name().into(),
+            (false, true) => format!("[alias {}]", metric.get_alias()),
+            (false, false) => "<unnamed>".into(),
+        };
+        println!("{:>8} {:>5.1}%  {}", size, percent(size), label);
+    }
+    let header = total - metrics;
+    println!(
+        "{:>8} {:>5.1}%  (timestamp, seq, uuid, body)",
+        header,
+        percent(header)
+    );
+    println!("{:>8} {:>5.1}%  total", total, 100.0);
+}
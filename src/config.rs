@@ -0,0 +1,550 @@
+use crate::dataset::{for_metric, DataSetBuilder};
+use crate::edge_node::{EdgeNode, OfflineData};
+use crate::parse::parse_value;
+use crate::properties::PropertySet;
+use crate::sparkplug_b::{Payload_Metric, Payload_Template_Parameter};
+use crate::template::{
+    create_parameter, declare_parameter, TemplateDefinition, TemplateInstance, TemplateRegistry,
+};
+use crate::{create_metric, create_null_metric, Clock, MetricDataType, SparkplugError};
+use serde::Deserialize;
+
+// ######################################################################
+// # Edge node configuration
+// ######################################################################
+// An edge node, its devices and their birth metrics described in XML:
+//
+//   <edgeNode group="Plant1" id="Line3" aliases="true">
+//     <primaryHost id="scada" queue="1000"/>
+//     <templates>
+//       <template name="Motor" version="1.0">
+//         <metrics><metric name="rpm" datatype="Int32" value="0"/></metrics>
+//         <parameters><parameter name="max" type="Int32" value="3000"/></parameters>
+//       </template>
+//     </templates>
+//     <metrics>
+//       <metric name="Temperature" datatype="Double" value="21.5" alias="10">
+//         <properties>
+//           <property key="engUnit" type="String" value="degC"/>
+//         </properties>
+//       </metric>
+//       <metric name="Motor1" datatype="Template" template="Motor">
+//         <metrics><metric name="rpm" datatype="Int32" value="1200"/></metrics>
+//       </metric>
+//       <metric name="Recipes" datatype="DataSet">
+//         <columns>
+//           <column name="id" type="Int32"/>
+//           <column name="label" type="String"/>
+//         </columns>
+//         <rows>
+//           <row><cell>1</cell><cell>warm up</cell></row>
+//           <row><cell>2</cell><cell null="true"/></row>
+//         </rows>
+//       </metric>
+//     </metrics>
+//     <devices>
+//       <device id="Pump1">
+//         <metrics><metric name="Running" datatype="Boolean" value="false"/></metrics>
+//       </device>
+//     </devices>
+//   </edgeNode>
+//
+// Values are in the text forms of `parse::parse_value`; a metric, parameter
+// or property without a value is null. A DataSet may also be given as the
+// JSON `value` instead of columns and rows, and PropertySet and
+// PropertySetList properties nest `<property>` and `<set>` elements.
+// Element text is trimmed.
+//
+// Elements of the same name must follow each other, hence the `<metrics>`,
+// `<devices>` and similar wrappers.
+// ######################################################################
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct EdgeNodeConfig {
+    pub group: String,
+    pub id: String,
+    // Publish DATA metrics by alias, see `EdgeNode::with_aliases`.
+    #[serde(default)]
+    pub aliases: bool,
+    #[serde(rename = "primaryHost", default)]
+    pub primary_host: Option<PrimaryHostConfig>,
+    #[serde(default)]
+    pub templates: TemplatesConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub devices: DevicesConfig,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct PrimaryHostConfig {
+    pub id: String,
+    // How many data messages to queue while the host is offline, none are
+    // kept without it.
+    #[serde(default)]
+    pub queue: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct TemplatesConfig {
+    #[serde(rename = "template", default)]
+    pub templates: Vec<TemplateConfig>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct TemplateConfig {
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub parameters: ParametersConfig,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct DevicesConfig {
+    #[serde(rename = "device", default)]
+    pub devices: Vec<DeviceConfig>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct DeviceConfig {
+    pub id: String,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct MetricsConfig {
+    #[serde(rename = "metric", default)]
+    pub metrics: Vec<MetricConfig>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct MetricConfig {
+    pub name: String,
+    pub datatype: MetricDataType,
+    #[serde(default)]
+    pub alias: Option<u64>,
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default)]
+    pub properties: Option<PropertiesConfig>,
+    // Template instances: the definition, its version, member values and
+    // parameter overrides.
+    #[serde(default)]
+    pub template: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub parameters: ParametersConfig,
+    // DataSets.
+    #[serde(default)]
+    pub columns: ColumnsConfig,
+    #[serde(default)]
+    pub rows: RowsConfig,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct ParametersConfig {
+    #[serde(rename = "parameter", default)]
+    pub parameters: Vec<ParameterConfig>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct ParameterConfig {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_type: MetricDataType,
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct PropertiesConfig {
+    #[serde(rename = "property", default)]
+    pub properties: Vec<PropertyConfig>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct PropertyConfig {
+    pub key: String,
+    #[serde(rename = "type")]
+    pub data_type: MetricDataType,
+    #[serde(default)]
+    pub value: Option<String>,
+    // The properties of a PropertySet.
+    #[serde(rename = "property", default)]
+    pub properties: Vec<PropertyConfig>,
+    // The sets of a PropertySetList.
+    #[serde(rename = "set", default)]
+    pub sets: Vec<PropertiesConfig>,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct ColumnsConfig {
+    #[serde(rename = "column", default)]
+    pub columns: Vec<ColumnConfig>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct ColumnConfig {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_type: MetricDataType,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct RowsConfig {
+    #[serde(rename = "row", default)]
+    pub rows: Vec<RowConfig>,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct RowConfig {
+    #[serde(rename = "cell", default)]
+    pub cells: Vec<CellConfig>,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq, Clone)]
+pub struct CellConfig {
+    #[serde(rename = "$value", default)]
+    pub value: Option<String>,
+    #[serde(default)]
+    pub null: bool,
+}
+
+impl EdgeNodeConfig {
+    pub fn from_xml(xml: &str) -> Result<EdgeNodeConfig, SparkplugError> {
+        quick_xml::de::from_str(xml).map_err(|e| SparkplugError::InvalidConfig(e.to_string()))
+    }
+
+    // The edge node with its templates and devices registered, and the
+    // metrics to pass to its `birth`. Metric timestamps are taken from `clock`,
+    // which becomes the clock of the node.
+    pub fn edge_node(
+        &self,
+        clock: Box<dyn Clock>,
+    ) -> Result<(EdgeNode, Vec<Payload_Metric>), SparkplugError> {
+        let mut templates = TemplateRegistry::new();
+        for template in &self.templates.templates {
+            templates.define(template.definition(clock.as_ref())?)?;
+        }
+        let metrics = self.metrics.build(clock.as_ref())?;
+        // Device metrics are checked by `add_device`, node metrics only at
+        // birth otherwise.
+        for metric in &metrics {
+            templates.validate_metric(metric)?;
+        }
+        let devices = self
+            .devices
+            .devices
+            .iter()
+            .map(|d| Ok((d.id.as_str(), d.metrics.build(clock.as_ref())?)))
+            .collect::<Result<Vec<(&str, Vec<Payload_Metric>)>, SparkplugError>>()?;
+
+        let mut node = EdgeNode::new(&self.group, &self.id)?
+            .with_clock(clock)
+            .with_templates(templates);
+        if self.aliases {
+            node = node.with_aliases();
+        }
+        if let Some(host) = &self.primary_host {
+            let offline_data = match host.queue {
+                Some(n) => OfflineData::Queue(n),
+                None => OfflineData::Drop,
+            };
+            node = node.with_primary_host(&host.id, offline_data);
+        }
+        for (device_id, metrics) in devices {
+            node.add_device(device_id, metrics)?;
+        }
+        Ok((node, metrics))
+    }
+}
+
+impl TemplateConfig {
+    pub fn definition(&self, clock: &dyn Clock) -> Result<TemplateDefinition, SparkplugError> {
+        let mut definition = TemplateDefinition::new(&self.name);
+        if let Some(version) = &self.version {
+            definition = definition.with_version(version);
+        }
+        for member in self.metrics.build(clock)? {
+            definition = definition.member(member);
+        }
+        for parameter in &self.parameters.parameters {
+            definition = definition.parameter(parameter.build()?);
+        }
+        Ok(definition)
+    }
+}
+
+impl MetricsConfig {
+    pub fn build(&self, clock: &dyn Clock) -> Result<Vec<Payload_Metric>, SparkplugError> {
+        self.metrics.iter().map(|m| m.build(clock)).collect()
+    }
+}
+
+impl MetricConfig {
+    pub fn build(&self, clock: &dyn Clock) -> Result<Payload_Metric, SparkplugError> {
+        let name = self.name.clone();
+        let mut metric = match (self.datatype, &self.value) {
+            (MetricDataType::Template, _) => self.template_instance(clock)?,
+            (MetricDataType::DataSet, None) if !self.columns.columns.is_empty() => {
+                self.dataset(clock)?
+            }
+            (data_type, Some(value)) => {
+                let value = parse_value(&name, data_type, value)?;
+                create_metric(clock, data_type, value, name, self.alias, None)?
+            }
+            (data_type, None) => create_null_metric(clock, data_type, name, self.alias, None)?,
+        };
+        if let Some(properties) = &self.properties {
+            metric.set_properties(properties.build()?.to_proto());
+        }
+        Ok(metric)
+    }
+
+    fn template_instance(&self, clock: &dyn Clock) -> Result<Payload_Metric, SparkplugError> {
+        let template_ref = self.template.as_deref().ok_or_else(|| {
+            SparkplugError::InvalidConfig(format!(
+                "template metric `{}` has no `template` attribute",
+                self.name
+            ))
+        })?;
+        let mut instance = TemplateInstance::new(template_ref);
+        if let Some(version) = &self.version {
+            instance = instance.with_version(version);
+        }
+        for member in self.metrics.build(clock)? {
+            instance = instance.member(member);
+        }
+        for parameter in &self.parameters.parameters {
+            instance = instance.parameter(parameter.build()?);
+        }
+        Ok(instance.metric(clock, self.name.clone(), self.alias))
+    }
+
+    fn dataset(&self, clock: &dyn Clock) -> Result<Payload_Metric, SparkplugError> {
+        let columns = &self.columns.columns;
+        let mut builder = DataSetBuilder::new();
+        for column in columns {
+            builder = builder
+                .column(&column.name, column.data_type)
+                .map_err(|e| for_metric(e, &self.name))?;
+        }
+        for row in &self.rows.rows {
+            let mut cells = vec![];
+            for (i, cell) in row.cells.iter().enumerate() {
+                let value = match (cell.null, columns.get(i)) {
+                    (true, _) | (_, None) => None,
+                    (false, Some(column)) => Some(parse_value(
+                        &column.name,
+                        column.data_type,
+                        cell.value.as_deref().unwrap_or_default(),
+                    )?),
+                };
+                cells.push(value);
+            }
+            builder = builder
+                .row_with_nulls(cells)
+                .map_err(|e| for_metric(e, &self.name))?;
+        }
+        Ok(builder.metric(clock, self.name.clone(), self.alias))
+    }
+}
+
+impl ParameterConfig {
+    pub fn build(&self) -> Result<Payload_Template_Parameter, SparkplugError> {
+        match &self.value {
+            Some(value) => create_parameter(
+                &self.name,
+                self.data_type,
+                parse_value(&self.name, self.data_type, value)?,
+            ),
+            None => declare_parameter(&self.name, self.data_type),
+        }
+    }
+}
+
+impl PropertiesConfig {
+    pub fn build(&self) -> Result<PropertySet, SparkplugError> {
+        self.properties
+            .iter()
+            .try_fold(PropertySet::new(), |set, property| property.add_to(set))
+    }
+}
+
+impl PropertyConfig {
+    fn add_to(&self, set: PropertySet) -> Result<PropertySet, SparkplugError> {
+        let key = &self.key;
+        match (self.data_type, &self.value) {
+            (MetricDataType::PropertySet, _) => {
+                let nested = PropertiesConfig {
+                    properties: self.properties.clone(),
+                };
+                Ok(set.insert_set(key, nested.build()?))
+            }
+            (MetricDataType::PropertySetList, _) => {
+                let list = self
+                    .sets
+                    .iter()
+                    .map(PropertiesConfig::build)
+                    .collect::<Result<Vec<PropertySet>, SparkplugError>>()?;
+                Ok(set.insert_list(key, list))
+            }
+            (data_type, Some(value)) => set.insert(key, parse_value(key, data_type, value)?),
+            (data_type, None) => set.insert_null(key, data_type),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::decode;
+    use crate::sparkplug_b::Payload;
+    use crate::state::{state_birth, StateFormat};
+    use crate::validate::validate;
+    use crate::{FixedClock, MetricValue, Topic};
+    use protobuf::Message;
+
+    // The example from the module header.
+    const EXAMPLE: &str = r#"
+        <edgeNode group="Plant1" id="Line3" aliases="true">
+          <primaryHost id="scada" queue="1000"/>
+          <templates>
+            <template name="Motor" version="1.0">
+              <metrics><metric name="rpm" datatype="Int32" value="0"/></metrics>
+              <parameters><parameter name="max" type="Int32" value="3000"/></parameters>
+            </template>
+          </templates>
+          <metrics>
+            <metric name="Temperature" datatype="Double" value="21.5" alias="10">
+              <properties>
+                <property key="engUnit" type="String" value="degC"/>
+              </properties>
+            </metric>
+            <metric name="Motor1" datatype="Template" template="Motor">
+              <metrics><metric name="rpm" datatype="Int32" value="1200"/></metrics>
+            </metric>
+            <metric name="Recipes" datatype="DataSet">
+              <columns>
+                <column name="id" type="Int32"/>
+                <column name="label" type="String"/>
+              </columns>
+              <rows>
+                <row><cell>1</cell><cell>warm up</cell></row>
+                <row><cell>2</cell><cell null="true"/></row>
+              </rows>
+            </metric>
+          </metrics>
+          <devices>
+            <device id="Pump1">
+              <metrics><metric name="Running" datatype="Boolean" value="false"/></metrics>
+            </device>
+          </devices>
+        </edgeNode>
+    "#;
+
+    #[test]
+    fn example_parses() {
+        let config = EdgeNodeConfig::from_xml(EXAMPLE).unwrap();
+        assert_eq!(
+            (config.group.as_str(), config.id.as_str()),
+            ("Plant1", "Line3")
+        );
+        assert!(config.aliases);
+        assert_eq!(
+            config.primary_host,
+            Some(PrimaryHostConfig {
+                id: "scada".into(),
+                queue: Some(1000),
+            })
+        );
+        assert_eq!(config.templates.templates[0].name, "Motor");
+        assert_eq!(config.templates.templates[0].parameters.parameters.len(), 1);
+
+        let metrics = &config.metrics.metrics;
+        assert_eq!(metrics.len(), 3);
+        assert_eq!(metrics[0].alias, Some(10));
+        assert_eq!(metrics[0].value.as_deref(), Some("21.5"));
+        assert_eq!(
+            metrics[0].properties.as_ref().unwrap().properties[0].key,
+            "engUnit"
+        );
+        assert_eq!(metrics[1].template.as_deref(), Some("Motor"));
+        assert_eq!(
+            metrics[2].columns.columns[1].data_type,
+            MetricDataType::String
+        );
+        let rows = &metrics[2].rows.rows;
+        assert_eq!(rows[0].cells[1].value.as_deref(), Some("warm up"));
+        assert!(rows[1].cells[1].null);
+        assert_eq!(config.devices.devices[0].id, "Pump1");
+    }
+
+    #[test]
+    fn example_births_are_valid() {
+        let config = EdgeNodeConfig::from_xml(EXAMPLE).unwrap();
+        let (mut node, metrics) = config.edge_node(Box::new(FixedClock::new(5))).unwrap();
+        let (topic, bytes) = state_birth("scada", 1, StateFormat::Json).unwrap();
+        node.handle_state(&topic, &bytes).unwrap();
+        node.connect().unwrap();
+        let births = node.birth(metrics).unwrap();
+        assert_eq!(births.len(), 2);
+        for (topic, bytes) in &births {
+            let payload = Payload::parse_from_bytes(bytes).unwrap();
+            assert_eq!(validate(topic, &payload), Vec::<String>::new());
+        }
+
+        let nbirth = decode(births[0].0.clone(), &births[0].1).unwrap();
+        let temperature = nbirth
+            .metrics
+            .iter()
+            .find(|m| m.name.as_deref() == Some("Temperature"))
+            .unwrap();
+        assert_eq!(temperature.value, Some(MetricValue::Double(21.5)));
+        assert_eq!(temperature.alias, Some(10));
+        let dbirth = decode(births[1].0.clone(), &births[1].1).unwrap();
+        assert_eq!(
+            births[1].0,
+            "spBv1.0/Plant1/DBIRTH/Line3/Pump1"
+                .parse::<Topic>()
+                .unwrap()
+        );
+        assert_eq!(dbirth.metrics[0].value, Some(MetricValue::Boolean(false)));
+    }
+
+    #[test]
+    fn unknown_template_is_rejected() {
+        let xml = r#"
+            <edgeNode group="G" id="E">
+              <metrics><metric name="M" datatype="Template" template="Nope"/></metrics>
+            </edgeNode>
+        "#;
+        let config = EdgeNodeConfig::from_xml(xml).unwrap();
+        assert!(config.edge_node(Box::new(FixedClock::new(1))).is_err());
+    }
+
+    #[test]
+    fn invalid_value_is_rejected() {
+        let xml = r#"
+            <edgeNode group="G" id="E">
+              <metrics><metric name="M" datatype="Int8" value="300"/></metrics>
+            </edgeNode>
+        "#;
+        let config = EdgeNodeConfig::from_xml(xml).unwrap();
+        assert!(matches!(
+            config.edge_node(Box::new(FixedClock::new(1))),
+            Err(SparkplugError::Parse { .. })
+        ));
+        assert!(matches!(
+            EdgeNodeConfig::from_xml("<edgeNode id=\"E\"/>"),
+            Err(SparkplugError::InvalidConfig(_))
+        ));
+    }
+}
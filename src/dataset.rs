@@ -0,0 +1,327 @@
+use crate::decode::{dataset_raw, is_scalar_type, scalar_value, validate_dataset};
+use crate::sparkplug_b::{
+    Payload_DataSet, Payload_DataSet_DataSetValue, Payload_DataSet_Row, Payload_Metric,
+};
+use crate::{
+    init_dataset_metric, set_scalar_value, Clock, MetricDataType, MetricValue, SparkplugError,
+};
+use protobuf::RepeatedField;
+
+// ######################################################################
+// # DataSets
+// ######################################################################
+// A DataSet is a table: named columns, each with a datatype, and rows with
+// one cell per column. A cell holds one of int, long, float, double, boolean
+// or string, so only the scalar datatypes can be used for columns. An empty
+// cell is a null.
+//
+//   let dataset = DataSetBuilder::new()
+//       .column("id", MetricDataType::UInt32)?
+//       .column("label", MetricDataType::String)?
+//       .row(vec![1u32.into(), "first".into()])?
+//       .build();
+// ######################################################################
+
+// Label used in errors, a DataSet does not know the metric it belongs to.
+const UNNAMED: &str = "<unnamed>";
+
+// Puts the name of the metric into an error the builder or reader reported
+// with the `<unnamed>` label.
+pub(crate) fn for_metric(e: SparkplugError, metric: &str) -> SparkplugError {
+    match e {
+        SparkplugError::InvalidDataSet { reason, .. } => SparkplugError::InvalidDataSet {
+            metric: metric.into(),
+            reason,
+        },
+        e => e,
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DataSetBuilder {
+    columns: Vec<String>,
+    types: Vec<MetricDataType>,
+    rows: Vec<Payload_DataSet_Row>,
+}
+
+impl DataSetBuilder {
+    pub fn new() -> DataSetBuilder {
+        DataSetBuilder::default()
+    }
+
+    pub fn column(mut self, name: &str, data_type: MetricDataType) -> Result<Self, SparkplugError> {
+        if !is_scalar_type(data_type) {
+            return Err(SparkplugError::UnsupportedType {
+                metric: name.into(),
+                data_type,
+            });
+        }
+        if !self.rows.is_empty() {
+            return Err(SparkplugError::InvalidDataSet {
+                metric: UNNAMED.into(),
+                reason: format!("column `{}` added after the first row", name),
+            });
+        }
+        self.columns.push(name.into());
+        self.types.push(data_type);
+        Ok(self)
+    }
+
+    pub fn row(self, values: Vec<MetricValue>) -> Result<Self, SparkplugError> {
+        self.row_with_nulls(values.into_iter().map(Some).collect())
+    }
+
+    // Like `row`, `None` cells are nulls.
+    pub fn row_with_nulls(
+        mut self,
+        values: Vec<Option<MetricValue>>,
+    ) -> Result<Self, SparkplugError> {
+        if values.len() != self.columns.len() {
+            return Err(SparkplugError::InvalidDataSet {
+                metric: UNNAMED.into(),
+                reason: format!(
+                    "row {} has {} values, expected {}",
+                    self.rows.len(),
+                    values.len(),
+                    self.columns.len()
+                ),
+            });
+        }
+        let mut row = Payload_DataSet_Row::new();
+        for (c, value) in values.into_iter().enumerate() {
+            let mut cell = Payload_DataSet_DataSetValue::new();
+            if let Some(value) = value {
+                let found = value.data_type();
+                let value =
+                    value
+                        .coerce(self.types[c])
+                        .ok_or_else(|| SparkplugError::TypeMismatch {
+                            metric: self.columns[c].clone(),
+                            data_type: self.types[c],
+                            found: format!("{:?}", found),
+                        })?;
+                set_scalar_value(&mut cell, value);
+            }
+            row.mut_elements().push(cell);
+        }
+        self.rows.push(row);
+        Ok(self)
+    }
+
+    pub fn build(self) -> Payload_DataSet {
+        let mut dataset = Payload_DataSet::new();
+        dataset.set_num_of_columns(self.columns.len() as u64);
+        dataset.set_columns(RepeatedField::from_vec(self.columns));
+        dataset.set_types(self.types.into_iter().map(|t| t as u32).collect());
+        dataset.set_rows(RepeatedField::from_vec(self.rows));
+        dataset
+    }
+
+    // A DataSet metric holding the built table.
+    pub fn metric(self, clock: &dyn Clock, name: String, alias: Option<u64>) -> Payload_Metric {
+        init_dataset_metric(clock, name, alias, self.build())
+    }
+}
+
+// Reads the rows of a DataSet as typed values, checking its shape first.
+pub struct DataSetReader<'a> {
+    dataset: &'a Payload_DataSet,
+    types: Vec<MetricDataType>,
+}
+
+impl<'a> DataSetReader<'a> {
+    pub fn new(dataset: &'a Payload_DataSet) -> Result<DataSetReader<'a>, SparkplugError> {
+        validate_dataset(dataset).map_err(|reason| SparkplugError::InvalidDataSet {
+            metric: UNNAMED.into(),
+            reason,
+        })?;
+        let types = dataset
+            .get_types()
+            .iter()
+            .filter_map(|t| MetricDataType::try_from(*t).ok())
+            .collect();
+        Ok(DataSetReader { dataset, types })
+    }
+
+    pub fn columns(&self) -> &[String] {
+        self.dataset.get_columns()
+    }
+
+    pub fn types(&self) -> &[MetricDataType] {
+        &self.types
+    }
+
+    pub fn len(&self) -> usize {
+        self.dataset.get_rows().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dataset.get_rows().is_empty()
+    }
+
+    // Each row as one value per column, `None` for a null cell.
+    pub fn rows(&self) -> impl Iterator<Item = Vec<Option<MetricValue>>> + '_ {
+        self.dataset.get_rows().iter().map(move |row| {
+            row.get_elements()
+                .iter()
+                .zip(&self.types)
+                .map(|(cell, t)| {
+                    cell.value
+                        .as_ref()
+                        .and_then(|v| scalar_value(*t, &dataset_raw(v)).ok())
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::decode_metric;
+    use crate::sparkplug_b::Payload;
+    use crate::FixedClock;
+    use chrono::{TimeZone, Utc};
+    use protobuf::Message as _;
+
+    fn table() -> Result<DataSetBuilder, SparkplugError> {
+        DataSetBuilder::new()
+            .column("id", MetricDataType::Int8)?
+            .column("count", MetricDataType::UInt64)?
+            .column("ratio", MetricDataType::Double)?
+            .column("ok", MetricDataType::Boolean)?
+            .column("label", MetricDataType::String)?
+            .column("at", MetricDataType::DateTime)
+    }
+
+    #[test]
+    fn built_dataset_reads_back_after_encoding() {
+        let at = Utc.timestamp_millis_opt(1256102875335).unwrap();
+        let builder = table()
+            .unwrap()
+            .row(vec![
+                (-3i8).into(),
+                7u32.into(),
+                0.5f32.into(),
+                true.into(),
+                "first".into(),
+                at.into(),
+            ])
+            .unwrap()
+            .row_with_nulls(vec![Some(4i8.into()), None, None, None, None, None])
+            .unwrap();
+
+        let clock = FixedClock::new(1000);
+        let mut payload = Payload::new();
+        payload
+            .mut_metrics()
+            .push(builder.metric(&clock, "table".into(), None));
+        let bytes = payload.write_to_bytes().unwrap();
+        let payload = Payload::parse_from_bytes(&bytes).unwrap();
+        let metric = decode_metric(&payload.get_metrics()[0]).unwrap();
+        let dataset = match metric.value {
+            Some(MetricValue::DataSet(dataset)) => dataset,
+            other => panic!("expected a DataSet, got {:?}", other),
+        };
+
+        let reader = DataSetReader::new(&dataset).unwrap();
+        assert_eq!(
+            reader.columns(),
+            ["id", "count", "ratio", "ok", "label", "at"]
+        );
+        assert_eq!(
+            reader.types(),
+            [
+                MetricDataType::Int8,
+                MetricDataType::UInt64,
+                MetricDataType::Double,
+                MetricDataType::Boolean,
+                MetricDataType::String,
+                MetricDataType::DateTime,
+            ]
+        );
+        assert_eq!(reader.len(), 2);
+        let rows: Vec<_> = reader.rows().collect();
+        assert_eq!(
+            rows,
+            [
+                vec![
+                    Some(MetricValue::Int8(-3)),
+                    Some(MetricValue::UInt64(7)),
+                    Some(MetricValue::Double(0.5)),
+                    Some(MetricValue::Boolean(true)),
+                    Some(MetricValue::String("first".into())),
+                    Some(MetricValue::DateTime(at)),
+                ],
+                vec![Some(MetricValue::Int8(4)), None, None, None, None, None],
+            ]
+        );
+    }
+
+    #[test]
+    fn cells_must_match_their_column_type() {
+        let values = |id: MetricValue| {
+            vec![
+                id,
+                1u64.into(),
+                1.0f64.into(),
+                false.into(),
+                "".into(),
+                0i64.into(),
+            ]
+        };
+        assert!(matches!(
+            table().unwrap().row(values(300i32.into())),
+            Err(SparkplugError::TypeMismatch { metric, data_type: MetricDataType::Int8, found })
+                if metric == "id" && found == "Int32"
+        ));
+        assert!(matches!(
+            table().unwrap().row(values("1".into())),
+            Err(SparkplugError::TypeMismatch { metric, .. }) if metric == "id"
+        ));
+        assert!(table().unwrap().row(values(1i32.into())).is_ok());
+    }
+
+    #[test]
+    fn rows_must_have_one_value_per_column() {
+        assert!(matches!(
+            table().unwrap().row(vec![1i8.into()]),
+            Err(SparkplugError::InvalidDataSet { reason, .. })
+                if reason == "row 0 has 1 values, expected 6"
+        ));
+        let builder = DataSetBuilder::new()
+            .column("a", MetricDataType::Int32)
+            .unwrap()
+            .row(vec![1i32.into()])
+            .unwrap();
+        assert!(matches!(
+            builder.column("b", MetricDataType::Int32),
+            Err(SparkplugError::InvalidDataSet { .. })
+        ));
+    }
+
+    #[test]
+    fn only_scalar_columns_are_allowed() {
+        for data_type in [
+            MetricDataType::Bytes,
+            MetricDataType::DataSet,
+            MetricDataType::Int32Array,
+            MetricDataType::Template,
+        ] {
+            assert!(matches!(
+                DataSetBuilder::new().column("c", data_type),
+                Err(SparkplugError::UnsupportedType { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn reader_rejects_inconsistent_datasets() {
+        let mut dataset = table().unwrap().build();
+        dataset.mut_types().pop();
+        assert!(matches!(
+            DataSetReader::new(&dataset),
+            Err(SparkplugError::InvalidDataSet { .. })
+        ));
+    }
+}
@@ -0,0 +1,800 @@
+use crate::properties::PropertySet;
+use crate::sparkplug_b::{
+    Payload, Payload_DataSet, Payload_DataSet_DataSetValue_oneof_value, Payload_MetaData,
+    Payload_Metric, Payload_Metric_oneof_value, Payload_Template,
+};
+use crate::{arrays, MessageType, MetricDataType, MetricValue, SparkplugError, Topic};
+use chrono::{TimeZone, Utc};
+use protobuf::Message as _;
+use serde::{Deserialize, Serialize};
+
+// ######################################################################
+// # Payload decoding
+// ######################################################################
+// Turns the generated protobuf types into `Message`/`Metric`, checking that
+// every value sits in the field its declared datatype calls for.
+//
+// BIRTH metrics must declare their datatype. Other messages may leave it out,
+// the value is then read by its populated field alone (int_value as UInt32,
+// long_value as UInt64, ...). Use `decode_metric_as` with the datatype from
+// the birth certificate to read such a value as its declared type.
+// ######################################################################
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Message {
+    pub topic: Topic,
+    pub timestamp: Option<u64>,
+    pub seq: Option<u64>,
+    pub uuid: Option<String>,
+    pub body: Option<Vec<u8>>,
+    pub metrics: Vec<Metric>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Metric {
+    pub name: Option<String>,
+    pub alias: Option<u64>,
+    pub timestamp: Option<u64>,
+    pub data_type: MetricDataType,
+    pub is_historical: bool,
+    pub is_transient: bool,
+    // `None` when the metric is null.
+    pub value: Option<MetricValue>,
+    pub metadata: Option<MetaData>,
+    pub properties: Option<PropertySet>,
+}
+
+impl Metric {
+    // A scalar property as a Rust type, e.g. `get_property::<f64>("engHigh")`.
+    pub fn get_property<T: TryFrom<MetricValue>>(&self, key: &str) -> Option<T> {
+        self.properties.as_ref().and_then(|p| p.get_as(key))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct MetaData {
+    #[serde(default)]
+    pub is_multi_part: Option<bool>,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(default)]
+    pub seq: Option<u64>,
+    #[serde(default)]
+    pub file_name: Option<String>,
+    #[serde(default)]
+    pub file_type: Option<String>,
+    #[serde(default)]
+    pub md5: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl From<&Payload_MetaData> for MetaData {
+    fn from(m: &Payload_MetaData) -> Self {
+        MetaData {
+            is_multi_part: m.has_is_multi_part().then(|| m.get_is_multi_part()),
+            content_type: m.has_content_type().then(|| m.get_content_type().into()),
+            size: m.has_size().then(|| m.get_size()),
+            seq: m.has_seq().then(|| m.get_seq()),
+            file_name: m.has_file_name().then(|| m.get_file_name().into()),
+            file_type: m.has_file_type().then(|| m.get_file_type().into()),
+            md5: m.has_md5().then(|| m.get_md5().into()),
+            description: m.has_description().then(|| m.get_description().into()),
+        }
+    }
+}
+
+impl From<MetaData> for Payload_MetaData {
+    fn from(m: MetaData) -> Self {
+        let mut metadata = Payload_MetaData::new();
+        if let Some(v) = m.is_multi_part {
+            metadata.set_is_multi_part(v);
+        }
+        if let Some(v) = m.content_type {
+            metadata.set_content_type(v);
+        }
+        if let Some(v) = m.size {
+            metadata.set_size(v);
+        }
+        if let Some(v) = m.seq {
+            metadata.set_seq(v);
+        }
+        if let Some(v) = m.file_name {
+            metadata.set_file_name(v);
+        }
+        if let Some(v) = m.file_type {
+            metadata.set_file_type(v);
+        }
+        if let Some(v) = m.md5 {
+            metadata.set_md5(v);
+        }
+        if let Some(v) = m.description {
+            metadata.set_description(v);
+        }
+        metadata
+    }
+}
+
+pub fn decode(topic: Topic, bytes: &[u8]) -> Result<Message, SparkplugError> {
+    if topic.message_type() == MessageType::STATE {
+        return Err(SparkplugError::StateMessage);
+    }
+    let payload = Payload::parse_from_bytes(bytes).map_err(SparkplugError::Decode)?;
+    decode_payload(topic, &payload)
+}
+
+pub fn decode_payload(topic: Topic, payload: &Payload) -> Result<Message, SparkplugError> {
+    decode_payload_with_types(topic, payload, |_| None)
+}
+
+// Like `decode_payload`, but a metric without a datatype is read as the type
+// `declared` returns for it, typically the one from the matching birth.
+pub fn decode_payload_with_types<F>(
+    topic: Topic,
+    payload: &Payload,
+    declared: F,
+) -> Result<Message, SparkplugError>
+where
+    F: Fn(&Payload_Metric) -> Option<MetricDataType>,
+{
+    if topic.message_type() == MessageType::STATE {
+        return Err(SparkplugError::StateMessage);
+    }
+    let birth = matches!(
+        topic.message_type(),
+        MessageType::NBIRTH | MessageType::DBIRTH
+    );
+    let metrics = payload
+        .get_metrics()
+        .iter()
+        .map(|m| {
+            if m.has_datatype() {
+                return decode_metric(m);
+            }
+            if birth {
+                return Err(SparkplugError::MissingDataType {
+                    metric: metric_label(m),
+                });
+            }
+            match declared(m) {
+                Some(data_type) => decode_metric_as(m, data_type),
+                None => decode_metric(m),
+            }
+        })
+        .collect::<Result<Vec<Metric>, SparkplugError>>()?;
+    Ok(Message {
+        topic,
+        timestamp: payload.has_timestamp().then(|| payload.get_timestamp()),
+        seq: payload.has_seq().then(|| payload.get_seq()),
+        uuid: payload.has_uuid().then(|| payload.get_uuid().into()),
+        body: payload.has_body().then(|| payload.get_body().to_vec()),
+        metrics,
+    })
+}
+
+// Decodes a metric with its own datatype, or the type implied by its value
+// field when it has none.
+pub fn decode_metric(metric: &Payload_Metric) -> Result<Metric, SparkplugError> {
+    decode_metric_as(metric, metric_data_type(metric)?)
+}
+
+// The datatype of a metric, or the one implied by its value field.
+pub(crate) fn metric_data_type(metric: &Payload_Metric) -> Result<MetricDataType, SparkplugError> {
+    if !metric.has_datatype() {
+        return Ok(implied_data_type(metric));
+    }
+    MetricDataType::try_from(metric.get_datatype()).map_err(|_| SparkplugError::UnknownDataType {
+        metric: metric_label(metric),
+        datatype: metric.get_datatype(),
+    })
+}
+
+// Decodes a metric as `data_type`, ignoring its own `datatype` field.
+pub fn decode_metric_as(
+    metric: &Payload_Metric,
+    data_type: MetricDataType,
+) -> Result<Metric, SparkplugError> {
+    let value = if metric.get_is_null() {
+        None
+    } else {
+        Some(metric_value(metric, data_type)?)
+    };
+    Ok(Metric {
+        name: metric.has_name().then(|| metric.get_name().into()),
+        alias: metric.has_alias().then(|| metric.get_alias()),
+        timestamp: metric.has_timestamp().then(|| metric.get_timestamp()),
+        data_type,
+        is_historical: metric.get_is_historical(),
+        is_transient: metric.get_is_transient(),
+        value,
+        metadata: metric.metadata.as_ref().map(MetaData::from),
+        properties: metric
+            .properties
+            .as_ref()
+            .map(PropertySet::from_proto)
+            .transpose()?,
+    })
+}
+
+// The name of a metric for error messages, falling back to its alias.
+pub(crate) fn metric_label(metric: &Payload_Metric) -> String {
+    if metric.has_name() {
+        metric.get_name().into()
+    } else if metric.has_alias() {
+        format!("alias {}", metric.get_alias())
+    } else {
+        "<unnamed>".into()
+    }
+}
+
+fn implied_data_type(metric: &Payload_Metric) -> MetricDataType {
+    match &metric.value {
+        Some(Payload_Metric_oneof_value::int_value(_)) => MetricDataType::UInt32,
+        Some(Payload_Metric_oneof_value::long_value(_)) => MetricDataType::UInt64,
+        Some(Payload_Metric_oneof_value::float_value(_)) => MetricDataType::Float,
+        Some(Payload_Metric_oneof_value::double_value(_)) => MetricDataType::Double,
+        Some(Payload_Metric_oneof_value::boolean_value(_)) => MetricDataType::Boolean,
+        Some(Payload_Metric_oneof_value::string_value(_)) => MetricDataType::String,
+        Some(Payload_Metric_oneof_value::bytes_value(_)) => MetricDataType::Bytes,
+        Some(Payload_Metric_oneof_value::dataset_value(_)) => MetricDataType::DataSet,
+        Some(Payload_Metric_oneof_value::template_value(_)) => MetricDataType::Template,
+        Some(Payload_Metric_oneof_value::extension_value(_)) | None => MetricDataType::Unknown,
+    }
+}
+
+fn metric_value(
+    metric: &Payload_Metric,
+    data_type: MetricDataType,
+) -> Result<MetricValue, SparkplugError> {
+    let label = || metric_label(metric);
+    let raw = match &metric.value {
+        None => {
+            return Err(SparkplugError::MissingValue {
+                metric: label(),
+                data_type,
+            })
+        }
+        Some(Payload_Metric_oneof_value::int_value(v)) => Raw::Int(*v),
+        Some(Payload_Metric_oneof_value::long_value(v)) => Raw::Long(*v),
+        Some(Payload_Metric_oneof_value::float_value(v)) => Raw::Float(*v),
+        Some(Payload_Metric_oneof_value::double_value(v)) => Raw::Double(*v),
+        Some(Payload_Metric_oneof_value::boolean_value(v)) => Raw::Boolean(*v),
+        Some(Payload_Metric_oneof_value::string_value(v)) => Raw::String(v),
+        Some(Payload_Metric_oneof_value::bytes_value(v)) => Raw::Bytes(v),
+        Some(Payload_Metric_oneof_value::dataset_value(v)) => Raw::DataSet(v),
+        Some(Payload_Metric_oneof_value::template_value(v)) => Raw::Template(v),
+        Some(Payload_Metric_oneof_value::extension_value(_)) => Raw::Extension,
+    };
+    let value =
+        scalar_value(data_type, &raw).map_err(|e| e.into_decode_error(label(), data_type))?;
+    match &value {
+        MetricValue::DataSet(dataset) => {
+            validate_dataset(dataset).map_err(|reason| SparkplugError::InvalidDataSet {
+                metric: label(),
+                reason,
+            })?
+        }
+        MetricValue::Template(template) => validate_template(template)?,
+        _ => (),
+    }
+    Ok(value)
+}
+
+// The value fields shared by metrics, dataset cells, template parameters and
+// property values.
+pub(crate) enum Raw<'a> {
+    Int(u32),
+    Long(u64),
+    Float(f32),
+    Double(f64),
+    Boolean(bool),
+    String(&'a str),
+    Bytes(&'a [u8]),
+    DataSet(&'a Payload_DataSet),
+    Template(&'a Payload_Template),
+    Extension,
+}
+
+impl Raw<'_> {
+    pub(crate) fn field(&self) -> &'static str {
+        match self {
+            Raw::Int(_) => "int_value",
+            Raw::Long(_) => "long_value",
+            Raw::Float(_) => "float_value",
+            Raw::Double(_) => "double_value",
+            Raw::Boolean(_) => "boolean_value",
+            Raw::String(_) => "string_value",
+            Raw::Bytes(_) => "bytes_value",
+            Raw::DataSet(_) => "dataset_value",
+            Raw::Template(_) => "template_value",
+            Raw::Extension => "extension_value",
+        }
+    }
+}
+
+pub(crate) enum ValueError {
+    Unsupported,
+    Mismatch(&'static str),
+    Invalid,
+}
+
+impl ValueError {
+    fn into_decode_error(self, metric: String, data_type: MetricDataType) -> SparkplugError {
+        match self {
+            ValueError::Unsupported => SparkplugError::UnsupportedType { metric, data_type },
+            ValueError::Mismatch(field) => SparkplugError::TypeMismatch {
+                metric,
+                data_type,
+                found: field.into(),
+            },
+            ValueError::Invalid => SparkplugError::InvalidValue { metric, data_type },
+        }
+    }
+}
+
+pub(crate) fn scalar_value(
+    data_type: MetricDataType,
+    raw: &Raw,
+) -> Result<MetricValue, ValueError> {
+    let mismatch = || ValueError::Mismatch(raw.field());
+    let value = match (data_type, raw) {
+        (MetricDataType::Int8, Raw::Int(v)) => MetricValue::Int8(
+            signed_int(*v, 8)
+                .and_then(|v| i8::try_from(v).ok())
+                .ok_or(ValueError::Invalid)?,
+        ),
+        (MetricDataType::Int16, Raw::Int(v)) => MetricValue::Int16(
+            signed_int(*v, 16)
+                .and_then(|v| i16::try_from(v).ok())
+                .ok_or(ValueError::Invalid)?,
+        ),
+        (MetricDataType::Int32, Raw::Int(v)) => MetricValue::Int32(*v as i32),
+        (MetricDataType::Int64, Raw::Long(v)) => MetricValue::Int64(*v as i64),
+        (MetricDataType::UInt8, Raw::Int(v)) => {
+            MetricValue::UInt8(u8::try_from(*v).map_err(|_| ValueError::Invalid)?)
+        }
+        (MetricDataType::UInt16, Raw::Int(v)) => {
+            MetricValue::UInt16(u16::try_from(*v).map_err(|_| ValueError::Invalid)?)
+        }
+        (MetricDataType::UInt32, Raw::Int(v)) => MetricValue::UInt32(*v),
+        (MetricDataType::UInt32, Raw::Long(v)) => {
+            // Some implementations send UInt32 in long_value to keep the full range.
+            MetricValue::UInt32(u32::try_from(*v).map_err(|_| ValueError::Invalid)?)
+        }
+        (MetricDataType::UInt64, Raw::Long(v)) => MetricValue::UInt64(*v),
+        (MetricDataType::Float, Raw::Float(v)) => MetricValue::Float(*v),
+        (MetricDataType::Double, Raw::Double(v)) => MetricValue::Double(*v),
+        (MetricDataType::Boolean, Raw::Boolean(v)) => MetricValue::Boolean(*v),
+        (MetricDataType::String, Raw::String(v)) => MetricValue::String(v.to_string()),
+        (MetricDataType::Text, Raw::String(v)) => MetricValue::Text(v.to_string()),
+        (MetricDataType::UUID, Raw::String(v)) => MetricValue::UUID(v.to_string()),
+        (MetricDataType::DateTime, Raw::Long(v)) => MetricValue::DateTime(
+            Utc.timestamp_millis_opt(*v as i64)
+                .single()
+                .ok_or(ValueError::Invalid)?,
+        ),
+        (MetricDataType::Bytes, Raw::Bytes(v)) => MetricValue::Bytes(v.to_vec()),
+        (MetricDataType::File, Raw::Bytes(v)) => MetricValue::File(v.to_vec()),
+        (MetricDataType::DataSet, Raw::DataSet(v)) => MetricValue::DataSet((*v).clone()),
+        (MetricDataType::Template, Raw::Template(v)) => MetricValue::Template((*v).clone()),
+        (t, Raw::Bytes(v)) if t.is_array() => arrays::decode(t, v).ok_or(ValueError::Invalid)?,
+        (
+            MetricDataType::Unknown | MetricDataType::PropertySet | MetricDataType::PropertySetList,
+            _,
+        ) => return Err(ValueError::Unsupported),
+        _ => return Err(mismatch()),
+    };
+    Ok(value)
+}
+
+// An Int8 or Int16 sent as the two's complement of its `bits` wide value,
+// e.g. 251 for Int8 -5. The value sign extended to 32 bits, 4294967291, as
+// written by some older implementations, is accepted too.
+fn signed_int(v: u32, bits: u32) -> Option<i32> {
+    if v >> bits == 0 {
+        let shift = 32 - bits;
+        Some(((v << shift) as i32) >> shift)
+    } else {
+        let v = v as i32;
+        (v < 0 && v >= -(1 << (bits - 1))).then_some(v)
+    }
+}
+
+// The datatypes `scalar_value` reads from int, long, float, double, boolean
+// or string, the only fields of DataSet cells and template parameters.
+pub(crate) fn is_scalar_type(data_type: MetricDataType) -> bool {
+    matches!(
+        data_type,
+        MetricDataType::Int8
+            | MetricDataType::Int16
+            | MetricDataType::Int32
+            | MetricDataType::Int64
+            | MetricDataType::UInt8
+            | MetricDataType::UInt16
+            | MetricDataType::UInt32
+            | MetricDataType::UInt64
+            | MetricDataType::Float
+            | MetricDataType::Double
+            | MetricDataType::Boolean
+            | MetricDataType::String
+            | MetricDataType::DateTime
+            | MetricDataType::Text
+            | MetricDataType::UUID
+    )
+}
+
+// Checks the shape of a DataSet and that every cell holds its column type.
+pub(crate) fn validate_dataset(dataset: &Payload_DataSet) -> Result<(), String> {
+    let columns = dataset.get_columns().len();
+    if dataset.has_num_of_columns() && dataset.get_num_of_columns() as usize != columns {
+        return Err(format!(
+            "num_of_columns is {} but there are {} columns",
+            dataset.get_num_of_columns(),
+            columns
+        ));
+    }
+    if dataset.get_types().len() != columns {
+        return Err(format!(
+            "{} columns but {} types",
+            columns,
+            dataset.get_types().len()
+        ));
+    }
+    let types = dataset
+        .get_types()
+        .iter()
+        .map(|t| MetricDataType::try_from(*t).map_err(|_| format!("unknown column type {}", t)))
+        .collect::<Result<Vec<MetricDataType>, String>>()?;
+    for (r, row) in dataset.get_rows().iter().enumerate() {
+        if row.get_elements().len() != columns {
+            return Err(format!(
+                "row {} has {} elements, expected {}",
+                r,
+                row.get_elements().len(),
+                columns
+            ));
+        }
+        for (c, cell) in row.get_elements().iter().enumerate() {
+            let raw = match &cell.value {
+                // An empty cell is a null.
+                None => continue,
+                Some(v) => dataset_raw(v),
+            };
+            if scalar_value(types[c], &raw).is_err() {
+                return Err(format!(
+                    "row {} column `{}` holds {} but is declared {:?}",
+                    r,
+                    dataset.get_columns()[c],
+                    raw.field(),
+                    types[c]
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn dataset_raw(value: &Payload_DataSet_DataSetValue_oneof_value) -> Raw<'_> {
+    match value {
+        Payload_DataSet_DataSetValue_oneof_value::int_value(v) => Raw::Int(*v),
+        Payload_DataSet_DataSetValue_oneof_value::long_value(v) => Raw::Long(*v),
+        Payload_DataSet_DataSetValue_oneof_value::float_value(v) => Raw::Float(*v),
+        Payload_DataSet_DataSetValue_oneof_value::double_value(v) => Raw::Double(*v),
+        Payload_DataSet_DataSetValue_oneof_value::boolean_value(v) => Raw::Boolean(*v),
+        Payload_DataSet_DataSetValue_oneof_value::string_value(v) => Raw::String(v),
+        Payload_DataSet_DataSetValue_oneof_value::extension_value(_) => Raw::Extension,
+    }
+}
+
+// Template members are metrics themselves, a definition must declare the
+// datatype of each of them.
+fn validate_template(template: &Payload_Template) -> Result<(), SparkplugError> {
+    for member in template.get_metrics() {
+        if template.get_is_definition() && !member.has_datatype() {
+            return Err(SparkplugError::MissingDataType {
+                metric: metric_label(member),
+            });
+        }
+        decode_metric(member)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataset::DataSetBuilder;
+    use crate::properties::PropertySet;
+    use crate::sparkplug_b::{
+        Payload_DataSet_DataSetValue, Payload_DataSet_Row, Payload_Metric_oneof_value,
+    };
+    use crate::{create_metric, FixedClock};
+    use protobuf::RepeatedField;
+
+    fn topic(message_type: MessageType) -> Topic {
+        Topic::node("group", message_type, "node").unwrap()
+    }
+
+    fn metric(
+        name: &str,
+        datatype: Option<MetricDataType>,
+        value: Payload_Metric_oneof_value,
+    ) -> Payload_Metric {
+        let mut metric = Payload_Metric::new();
+        metric.set_name(name.into());
+        if let Some(t) = datatype {
+            metric.set_datatype(t as u32);
+        }
+        metric.value = Some(value);
+        metric
+    }
+
+    fn payload(metrics: Vec<Payload_Metric>) -> Payload {
+        let mut payload = Payload::new();
+        payload.set_metrics(RepeatedField::from_vec(metrics));
+        payload
+    }
+
+    fn cell(value: Payload_DataSet_DataSetValue_oneof_value) -> Payload_DataSet_DataSetValue {
+        let mut cell = Payload_DataSet_DataSetValue::new();
+        cell.value = Some(value);
+        cell
+    }
+
+    // Two columns, Int32 and String, and one row.
+    fn dataset() -> Payload_DataSet {
+        let mut row = Payload_DataSet_Row::new();
+        row.set_elements(RepeatedField::from_vec(vec![
+            cell(Payload_DataSet_DataSetValue_oneof_value::int_value(7)),
+            cell(Payload_DataSet_DataSetValue_oneof_value::string_value(
+                "a".into(),
+            )),
+        ]));
+        let mut dataset = Payload_DataSet::new();
+        dataset.set_num_of_columns(2);
+        dataset.set_columns(RepeatedField::from_vec(vec!["n".into(), "s".into()]));
+        dataset.set_types(vec![
+            MetricDataType::Int32 as u32,
+            MetricDataType::String as u32,
+        ]);
+        dataset.set_rows(RepeatedField::from_vec(vec![row]));
+        dataset
+    }
+
+    fn decode_dataset(dataset: Payload_DataSet) -> Result<Metric, SparkplugError> {
+        decode_metric(&metric(
+            "table",
+            Some(MetricDataType::DataSet),
+            Payload_Metric_oneof_value::dataset_value(dataset),
+        ))
+    }
+
+    fn invalid_dataset(dataset: Payload_DataSet) -> String {
+        match decode_dataset(dataset) {
+            Err(SparkplugError::InvalidDataSet { metric, reason }) => {
+                assert_eq!(metric, "table");
+                reason
+            }
+            other => panic!("expected an invalid DataSet, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn birth_metrics_must_declare_their_datatype() {
+        let message = payload(vec![metric(
+            "m",
+            None,
+            Payload_Metric_oneof_value::int_value(1),
+        )]);
+        for message_type in [MessageType::NBIRTH, MessageType::DBIRTH] {
+            let topic = match message_type {
+                MessageType::DBIRTH => {
+                    Topic::device("group", message_type, "node", "device").unwrap()
+                }
+                _ => topic(message_type),
+            };
+            assert!(matches!(
+                decode_payload(topic, &message),
+                Err(SparkplugError::MissingDataType { metric }) if metric == "m"
+            ));
+        }
+        let data = decode_payload(topic(MessageType::NDATA), &message).unwrap();
+        assert_eq!(data.metrics[0].data_type, MetricDataType::UInt32);
+        assert_eq!(data.metrics[0].value, Some(MetricValue::UInt32(1)));
+    }
+
+    #[test]
+    fn data_metrics_can_be_read_as_their_birth_type() {
+        let data = metric("m", None, Payload_Metric_oneof_value::int_value(300));
+        let decoded = decode_metric_as(&data, MetricDataType::Int16).unwrap();
+        assert_eq!(decoded.data_type, MetricDataType::Int16);
+        assert_eq!(decoded.value, Some(MetricValue::Int16(300)));
+        assert!(matches!(
+            decode_metric_as(&data, MetricDataType::UInt8),
+            Err(SparkplugError::InvalidValue {
+                data_type: MetricDataType::UInt8,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn value_must_sit_in_the_datatype_field() {
+        let m = metric(
+            "m",
+            Some(MetricDataType::Int32),
+            Payload_Metric_oneof_value::long_value(1),
+        );
+        assert!(matches!(
+            decode_metric(&m),
+            Err(SparkplugError::TypeMismatch { found, .. }) if found == "long_value"
+        ));
+        let mut m = Payload_Metric::new();
+        m.set_alias(4);
+        m.set_datatype(MetricDataType::Boolean as u32);
+        assert!(matches!(
+            decode_metric(&m),
+            Err(SparkplugError::MissingValue { metric, .. }) if metric == "alias 4"
+        ));
+        m.set_is_null(true);
+        assert_eq!(decode_metric(&m).unwrap().value, None);
+        m.set_datatype(99);
+        assert!(matches!(
+            decode_metric(&m),
+            Err(SparkplugError::UnknownDataType { datatype: 99, .. })
+        ));
+    }
+
+    #[test]
+    fn state_topics_are_not_decoded() {
+        assert!(matches!(
+            decode(Topic::state("host").unwrap(), b"ONLINE"),
+            Err(SparkplugError::StateMessage)
+        ));
+    }
+
+    #[test]
+    fn valid_dataset_decodes() {
+        let decoded = decode_dataset(dataset()).unwrap();
+        assert_eq!(decoded.value, Some(MetricValue::DataSet(dataset())));
+    }
+
+    #[test]
+    fn dataset_column_counts_must_agree() {
+        let mut d = dataset();
+        d.set_num_of_columns(3);
+        assert_eq!(
+            invalid_dataset(d),
+            "num_of_columns is 3 but there are 2 columns"
+        );
+
+        let mut d = dataset();
+        d.mut_types().push(MetricDataType::Int32 as u32);
+        assert_eq!(invalid_dataset(d), "2 columns but 3 types");
+
+        let mut d = dataset();
+        d.mut_rows()[0].mut_elements().pop();
+        assert_eq!(invalid_dataset(d), "row 0 has 1 elements, expected 2");
+    }
+
+    #[test]
+    fn dataset_cells_must_match_their_column_type() {
+        let mut d = dataset();
+        d.mut_rows()[0].mut_elements()[1] = cell(
+            Payload_DataSet_DataSetValue_oneof_value::boolean_value(true),
+        );
+        assert_eq!(
+            invalid_dataset(d),
+            "row 0 column `s` holds boolean_value but is declared String"
+        );
+
+        let mut d = dataset();
+        d.mut_types()[0] = 99;
+        assert_eq!(invalid_dataset(d), "unknown column type 99");
+
+        let mut d = dataset();
+        d.mut_rows()[0].mut_elements()[0] = Payload_DataSet_DataSetValue::new();
+        assert!(decode_dataset(d).is_ok());
+    }
+
+    #[test]
+    fn data_metrics_take_the_type_declared_in_the_birth() {
+        let message = payload(vec![
+            metric("small", None, Payload_Metric_oneof_value::int_value(200)),
+            metric("other", None, Payload_Metric_oneof_value::int_value(200)),
+            metric(
+                "own",
+                Some(MetricDataType::UInt16),
+                Payload_Metric_oneof_value::int_value(200),
+            ),
+        ]);
+        let declared = |m: &Payload_Metric| match m.get_name() {
+            "small" | "own" => Some(MetricDataType::UInt8),
+            _ => None,
+        };
+        let data =
+            decode_payload_with_types(topic(MessageType::NDATA), &message, declared).unwrap();
+        let values: Vec<_> = data.metrics.iter().map(|m| m.value.clone()).collect();
+        assert_eq!(
+            values,
+            [
+                Some(MetricValue::UInt8(200)),
+                Some(MetricValue::UInt32(200)),
+                Some(MetricValue::UInt16(200)),
+            ]
+        );
+        assert!(matches!(
+            decode_payload_with_types(topic(MessageType::NBIRTH), &message, declared),
+            Err(SparkplugError::MissingDataType { metric }) if metric == "small"
+        ));
+    }
+
+    fn round_trip(data_type: MetricDataType, value: MetricValue) -> Payload_Metric {
+        let clock = FixedClock::new(1);
+        let metric = create_metric(&clock, data_type, value.clone(), "m".into(), None, None);
+        let metric = metric.unwrap();
+        assert_eq!(decode_metric(&metric).unwrap().value, Some(value));
+        metric
+    }
+
+    fn decode_int(data_type: MetricDataType, v: u32) -> Option<MetricValue> {
+        let mut metric = Payload_Metric::new();
+        metric.set_datatype(data_type as u32);
+        metric.set_int_value(v);
+        decode_metric(&metric).ok().and_then(|m| m.value)
+    }
+
+    #[test]
+    fn int8_is_twos_complement_of_8_bits() {
+        let metric = round_trip(MetricDataType::Int8, MetricValue::Int8(-1));
+        assert_eq!(metric.get_int_value(), 255);
+        let metric = round_trip(MetricDataType::Int8, MetricValue::Int8(i8::MIN));
+        assert_eq!(metric.get_int_value(), 128);
+        assert_eq!(
+            decode_int(MetricDataType::Int8, 255),
+            Some(MetricValue::Int8(-1))
+        );
+        assert_eq!(
+            decode_int(MetricDataType::Int8, 127),
+            Some(MetricValue::Int8(127))
+        );
+        // Sign extended to 32 bits, as older implementations send it.
+        assert_eq!(
+            decode_int(MetricDataType::Int8, u32::MAX),
+            Some(MetricValue::Int8(-1))
+        );
+        assert_eq!(decode_int(MetricDataType::Int8, 256), None);
+    }
+
+    #[test]
+    fn int16_and_int32_are_twos_complement_of_their_width() {
+        let metric = round_trip(MetricDataType::Int16, MetricValue::Int16(-5));
+        assert_eq!(metric.get_int_value(), 65531);
+        let metric = round_trip(MetricDataType::Int32, MetricValue::Int32(-5));
+        assert_eq!(metric.get_int_value(), 4294967291);
+        assert_eq!(decode_int(MetricDataType::Int16, 1 << 16), None);
+    }
+
+    #[test]
+    fn int64_negatives_use_the_full_long_value() {
+        let metric = round_trip(MetricDataType::Int64, MetricValue::Int64(-1));
+        assert_eq!(metric.get_long_value(), u64::MAX);
+        let metric = round_trip(MetricDataType::Int64, MetricValue::Int64(i64::MIN));
+        assert_eq!(metric.get_long_value(), 1 << 63);
+    }
+
+    #[test]
+    fn cells_and_properties_use_the_same_encoding() {
+        let dataset = DataSetBuilder::new()
+            .column("c", MetricDataType::Int8)
+            .unwrap()
+            .row(vec![MetricValue::Int8(-1)])
+            .unwrap()
+            .build();
+        assert_eq!(dataset.get_rows()[0].get_elements()[0].get_int_value(), 255);
+        let properties = PropertySet::new().insert("p", -2i64).unwrap().to_proto();
+        assert_eq!(properties.get_values()[0].get_long_value(), u64::MAX - 1);
+    }
+}
@@ -0,0 +1,958 @@
+use crate::alias::AliasRegistry;
+use crate::host::REBIRTH_METRIC;
+use crate::sparkplug_b::{Payload, Payload_Metric};
+use crate::state::{decode_state, HostState};
+use crate::template::{is_definition, TemplateRegistry};
+use crate::{
+    get_d_data_payload, get_device_birth_payload, get_device_death_payload, get_node_birth_payload,
+    node_death_payload, Clock, MessageType, SequenceState, SparkplugError, SystemClock, Topic,
+    TopicError,
+};
+use protobuf::{Message, RepeatedField};
+use std::collections::{BTreeMap, VecDeque};
+
+// ######################################################################
+// # Edge node session
+// ######################################################################
+// The lifecycle of a Sparkplug edge node is
+//
+//   connect()  -> NDEATH to register as the MQTT will, bdSeq is consumed
+//   birth()    -> NBIRTH with seq 0 and the same bdSeq as the will
+//   data()     -> NDATA, only once born
+//   rebirth()  -> NBIRTH again, e.g. after a `Node Control/Rebirth` NCMD
+//   death()    -> NDEATH sent explicitly before a clean disconnect
+//
+// Devices are registered with `add_device` and are born together with the
+// node: every NBIRTH, including a rebirth, is followed by a DBIRTH for each
+// registered device.
+//
+// With `with_aliases` every birth metric is given an alias, unique across the
+// node and its devices, and data is still passed in by name but published
+// with the alias only.
+//
+// With `with_primary_host` the node follows the STATE of that host
+// application, passed in through `handle_state`. NBIRTH is refused until the
+// host is online, data is queued or dropped while it is offline, and the
+// returned `PrimaryHostEvent` says when to publish NDEATH and disconnect, or
+// to reconnect and publish the births again. Queued data is published as
+// historical right after the next births.
+//
+// Template definitions registered with `with_templates`, or passed among the
+// NBIRTH metrics, are published in every NBIRTH, and every template instance
+// in a birth is checked against its definition.
+//
+// Nothing here talks to a broker, every step returns the topic and the
+// encoded payload so any MQTT client can be used.
+// ######################################################################
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NodeState {
+    // No MQTT session, or the session has ended.
+    Disconnected,
+    // The NDEATH will has been built but no NBIRTH was published yet.
+    Connected,
+    // NBIRTH has been published, data may flow.
+    Born,
+}
+
+// What happens to data passed in while the primary host is offline.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OfflineData {
+    Drop,
+    // Keep at most this many messages, the oldest are dropped first.
+    Queue(usize),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PrimaryHostEvent {
+    // Another host, a STATE older than the last one seen, or the same state
+    // repeated.
+    Ignored,
+    // The host is online, connect if needed and publish the births.
+    Online,
+    // The host went offline. Publish the NDEATH, if any, and disconnect.
+    Offline(Option<(Topic, Vec<u8>)>),
+    // The host came back with a new session while the node was connected.
+    // Publish the NDEATH, disconnect, then `connect` and `birth` again.
+    Restarted(Topic, Vec<u8>),
+}
+
+struct PrimaryHost {
+    host_id: String,
+    offline_data: OfflineData,
+    // The last STATE received, `None` until the first one.
+    state: Option<HostState>,
+}
+
+struct Device {
+    // The metric set declared in DBIRTH, kept up to date by DDATA so a rebirth
+    // carries the latest values.
+    metrics: Vec<Payload_Metric>,
+    born: bool,
+}
+
+pub struct EdgeNode {
+    group_id: String,
+    edge_node_id: String,
+    sequences: SequenceState,
+    clock: Box<dyn Clock>,
+    // bdSeq of the will registered for the current MQTT session.
+    bd_seq: Option<u64>,
+    state: NodeState,
+    devices: BTreeMap<String, Device>,
+    // `None` when metrics are published by name.
+    aliases: Option<AliasRegistry>,
+    templates: TemplateRegistry,
+    primary_host: Option<PrimaryHost>,
+    // Data held back while the primary host is offline, by device id.
+    queued: VecDeque<(Option<String>, Vec<Payload_Metric>)>,
+}
+
+impl EdgeNode {
+    pub fn new(group_id: &str, edge_node_id: &str) -> Result<EdgeNode, TopicError> {
+        EdgeNode::with_sequences(group_id, edge_node_id, SequenceState::new())
+    }
+
+    // Use this to carry a persisted bdSeq over a process restart.
+    pub fn with_sequences(
+        group_id: &str,
+        edge_node_id: &str,
+        sequences: SequenceState,
+    ) -> Result<EdgeNode, TopicError> {
+        // Validates both ids.
+        Topic::node(group_id, MessageType::NBIRTH, edge_node_id)?;
+        Ok(EdgeNode {
+            group_id: group_id.into(),
+            edge_node_id: edge_node_id.into(),
+            sequences,
+            clock: Box::new(SystemClock),
+            bd_seq: None,
+            state: NodeState::Disconnected,
+            devices: BTreeMap::new(),
+            aliases: None,
+            templates: TemplateRegistry::new(),
+            primary_host: None,
+            queued: VecDeque::new(),
+        })
+    }
+
+    // Replaces the system clock, e.g. with a `FixedClock` for reproducible payloads.
+    pub fn with_clock(mut self, clock: Box<dyn Clock>) -> EdgeNode {
+        self.clock = clock;
+        self
+    }
+
+    // Publishes DATA metrics by alias, see `AliasRegistry`.
+    pub fn with_aliases(mut self) -> EdgeNode {
+        self.aliases = Some(AliasRegistry::new());
+        self
+    }
+
+    pub fn aliases(&self) -> Option<&AliasRegistry> {
+        self.aliases.as_ref()
+    }
+
+    // The template definitions to publish in NBIRTH.
+    pub fn with_templates(mut self, templates: TemplateRegistry) -> EdgeNode {
+        self.templates = templates;
+        self
+    }
+
+    pub fn templates(&self) -> &TemplateRegistry {
+        &self.templates
+    }
+
+    // Waits for `host_id` to be online before publishing, see `handle_state`.
+    pub fn with_primary_host(mut self, host_id: &str, offline_data: OfflineData) -> EdgeNode {
+        self.primary_host = Some(PrimaryHost {
+            host_id: host_id.into(),
+            offline_data,
+            state: None,
+        });
+        self
+    }
+
+    pub fn primary_host_id(&self) -> Option<&str> {
+        self.primary_host.as_ref().map(|h| h.host_id.as_str())
+    }
+
+    // The last STATE received from the primary host.
+    pub fn primary_host_state(&self) -> Option<HostState> {
+        self.primary_host.as_ref().and_then(|h| h.state)
+    }
+
+    // False while a primary host is configured and not known to be online.
+    pub fn may_publish(&self) -> bool {
+        match &self.primary_host {
+            None => true,
+            Some(host) => host.state.is_some_and(|s| s.online),
+        }
+    }
+
+    // Number of messages waiting for the primary host.
+    pub fn queued(&self) -> usize {
+        self.queued.len()
+    }
+
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    pub fn group_id(&self) -> &str {
+        &self.group_id
+    }
+
+    pub fn edge_node_id(&self) -> &str {
+        &self.edge_node_id
+    }
+
+    pub fn state(&self) -> NodeState {
+        self.state
+    }
+
+    // bdSeq of the current session, `None` before the first `connect`.
+    pub fn bd_seq(&self) -> Option<u64> {
+        self.bd_seq
+    }
+
+    pub fn sequences(&self) -> &SequenceState {
+        &self.sequences
+    }
+
+    pub fn topic(&self, message_type: MessageType) -> Topic {
+        Topic::Edge {
+            group_id: self.group_id.clone(),
+            message_type,
+            edge_node_id: self.edge_node_id.clone(),
+            device_id: None,
+        }
+    }
+
+    pub fn device_topic(&self, message_type: MessageType, device_id: &str) -> Topic {
+        Topic::Edge {
+            group_id: self.group_id.clone(),
+            message_type,
+            edge_node_id: self.edge_node_id.clone(),
+            device_id: Some(device_id.into()),
+        }
+    }
+
+    pub fn device_ids(&self) -> impl Iterator<Item = &str> {
+        self.devices.keys().map(String::as_str)
+    }
+
+    pub fn is_device_born(&self, device_id: &str) -> bool {
+        self.devices.get(device_id).is_some_and(|d| d.born)
+    }
+
+    // The metrics the device declared in its DBIRTH, with the latest values.
+    pub fn device_metrics(&self, device_id: &str) -> Option<&[Payload_Metric]> {
+        self.devices.get(device_id).map(|d| d.metrics.as_slice())
+    }
+
+    // Starts a new MQTT session. The returned NDEATH must be registered as the
+    // will message of the connection, its bdSeq is reused by the next NBIRTH.
+    // The previous session must have ended, see `disconnected` and `death`.
+    pub fn connect(&mut self) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        if self.state != NodeState::Disconnected {
+            return Err(SparkplugError::AlreadyConnected);
+        }
+        let bd_seq = self.sequences.next_bd_seq();
+        let payload = node_death_payload(self.clock.as_ref(), bd_seq)?;
+        self.bd_seq = Some(bd_seq);
+        self.state = NodeState::Connected;
+        self.encode(MessageType::NDEATH, &payload)
+    }
+
+    // Returns the NBIRTH followed by a DBIRTH for every registered device.
+    pub fn birth(
+        &mut self,
+        metrics: Vec<Payload_Metric>,
+    ) -> Result<Vec<(Topic, Vec<u8>)>, SparkplugError> {
+        match self.state {
+            NodeState::Disconnected => Err(SparkplugError::NotConnected),
+            NodeState::Born => Err(SparkplugError::AlreadyBorn),
+            NodeState::Connected => self.publish_birth(metrics),
+        }
+    }
+
+    // Publishes a fresh NBIRTH within the same MQTT session, so the bdSeq is
+    // unchanged and seq restarts at 0.
+    pub fn rebirth(
+        &mut self,
+        metrics: Vec<Payload_Metric>,
+    ) -> Result<Vec<(Topic, Vec<u8>)>, SparkplugError> {
+        match self.state {
+            NodeState::Disconnected => Err(SparkplugError::NotConnected),
+            NodeState::Connected => Err(SparkplugError::NotBorn),
+            NodeState::Born => self.publish_birth(metrics),
+        }
+    }
+
+    // `None` when the data was queued or dropped because the primary host is
+    // offline.
+    pub fn data(
+        &mut self,
+        metrics: Vec<Payload_Metric>,
+    ) -> Result<Option<(Topic, Vec<u8>)>, SparkplugError> {
+        if !self.may_publish() {
+            self.hold_back(None, metrics);
+            return Ok(None);
+        }
+        if self.state != NodeState::Born {
+            return Err(SparkplugError::NotBorn);
+        }
+        self.publish_data(None, metrics).map(Some)
+    }
+
+    // NDEATH to publish explicitly before a clean disconnect. It carries the
+    // bdSeq of the current session rather than consuming a new one.
+    pub fn death(&mut self) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        let bd_seq = self.bd_seq.ok_or(SparkplugError::NotConnected)?;
+        let payload = node_death_payload(self.clock.as_ref(), bd_seq)?;
+        self.disconnected();
+        self.encode(MessageType::NDEATH, &payload)
+    }
+
+    // Follows the STATE messages of the primary host, any other topic is an
+    // error and other hosts are ignored.
+    pub fn handle_state(
+        &mut self,
+        topic: &Topic,
+        bytes: &[u8],
+    ) -> Result<PrimaryHostEvent, SparkplugError> {
+        let (host_id, state) = decode_state(topic, bytes)?;
+        let host = match &mut self.primary_host {
+            Some(host) if host.host_id == host_id => host,
+            _ => return Ok(PrimaryHostEvent::Ignored),
+        };
+        let previous = host.state;
+        // Only a later timestamp tells a new session of an online host apart
+        // from a repeated message. The 2.x form has none, so there a restart
+        // shows up as OFFLINE then ONLINE.
+        let newer = match (previous.and_then(|s| s.timestamp), state.timestamp) {
+            (Some(last), Some(timestamp)) if timestamp < last => {
+                return Ok(PrimaryHostEvent::Ignored)
+            }
+            (Some(last), Some(timestamp)) => timestamp > last,
+            _ => false,
+        };
+        host.state = Some(state);
+        let was_online = previous.is_some_and(|s| s.online);
+        match (state.online, was_online) {
+            (true, false) => Ok(PrimaryHostEvent::Online),
+            (true, true) if !newer => Ok(PrimaryHostEvent::Ignored),
+            (true, true) if self.state == NodeState::Disconnected => Ok(PrimaryHostEvent::Online),
+            (true, true) => {
+                let (topic, bytes) = self.death()?;
+                Ok(PrimaryHostEvent::Restarted(topic, bytes))
+            }
+            (false, false) if previous.is_some() => Ok(PrimaryHostEvent::Ignored),
+            (false, _) if self.state == NodeState::Disconnected => {
+                Ok(PrimaryHostEvent::Offline(None))
+            }
+            (false, _) => self
+                .death()
+                .map(|death| PrimaryHostEvent::Offline(Some(death))),
+        }
+    }
+
+    // Marks the session as lost without publishing anything, the broker
+    // delivers the will registered by `connect`.
+    pub fn disconnected(&mut self) {
+        self.state = NodeState::Disconnected;
+        for device in self.devices.values_mut() {
+            device.born = false;
+        }
+    }
+
+    // Registers a device with the metric set it declares in DBIRTH. The DBIRTH
+    // is returned right away when the node is born, otherwise it is sent after
+    // the next NBIRTH.
+    pub fn add_device(
+        &mut self,
+        device_id: &str,
+        mut metrics: Vec<Payload_Metric>,
+    ) -> Result<Option<(Topic, Vec<u8>)>, SparkplugError> {
+        Topic::device(
+            &self.group_id,
+            MessageType::DBIRTH,
+            &self.edge_node_id,
+            device_id,
+        )?;
+        if self.devices.contains_key(device_id) {
+            return Err(SparkplugError::DuplicateDevice(device_id.into()));
+        }
+        for metric in &metrics {
+            self.templates.validate_metric(metric)?;
+        }
+        if let Some(aliases) = &mut self.aliases {
+            aliases.assign(Some(device_id), &mut metrics)?;
+        }
+        self.devices.insert(
+            device_id.into(),
+            Device {
+                metrics,
+                born: false,
+            },
+        );
+        if self.state != NodeState::Born {
+            return Ok(None);
+        }
+        self.publish_device_birth(device_id).map(Some)
+    }
+
+    // Unregisters a device, returning its DDEATH if it was born.
+    pub fn remove_device(
+        &mut self,
+        device_id: &str,
+    ) -> Result<Option<(Topic, Vec<u8>)>, SparkplugError> {
+        let device = self
+            .devices
+            .remove(device_id)
+            .ok_or_else(|| SparkplugError::UnknownDevice(device_id.into()))?;
+        if let Some(aliases) = &mut self.aliases {
+            aliases.clear_device(device_id);
+        }
+        if !device.born || self.state != NodeState::Born {
+            return Ok(None);
+        }
+        let payload = get_device_death_payload(self.clock.as_ref(), &self.sequences);
+        self.encode_device(MessageType::DDEATH, device_id, &payload)
+            .map(Some)
+    }
+
+    // `None` when the data was queued or dropped because the primary host is
+    // offline.
+    pub fn device_data(
+        &mut self,
+        device_id: &str,
+        metrics: Vec<Payload_Metric>,
+    ) -> Result<Option<(Topic, Vec<u8>)>, SparkplugError> {
+        let may_publish = self.may_publish();
+        if may_publish && self.state != NodeState::Born {
+            return Err(SparkplugError::NotBorn);
+        }
+        let device = self
+            .devices
+            .get_mut(device_id)
+            .ok_or_else(|| SparkplugError::UnknownDevice(device_id.into()))?;
+        if may_publish && !device.born {
+            return Err(SparkplugError::DeviceNotBorn(device_id.into()));
+        }
+        for metric in &metrics {
+            if let Some(declared) = device
+                .metrics
+                .iter_mut()
+                .find(|m| m.has_name() && m.get_name() == metric.get_name())
+            {
+                update_value(declared, metric);
+            }
+        }
+        if !may_publish {
+            self.hold_back(Some(device_id), metrics);
+            return Ok(None);
+        }
+        self.publish_data(Some(device_id), metrics).map(Some)
+    }
+
+    fn publish_birth(
+        &mut self,
+        mut metrics: Vec<Payload_Metric>,
+    ) -> Result<Vec<(Topic, Vec<u8>)>, SparkplugError> {
+        let bd_seq = self.bd_seq.ok_or(SparkplugError::NotConnected)?;
+        if !self.may_publish() {
+            return Err(SparkplugError::PrimaryHostOffline);
+        }
+        // `Node Control/Rebirth` is always added by `get_node_birth_payload`.
+        metrics.retain(|m| m.get_name() != REBIRTH_METRIC);
+        // The registries are updated on copies, kept only once every message
+        // has been built, so a failure leaves the session as it was.
+        let mut templates = self.templates.clone();
+        templates.define_from_birth(&metrics)?;
+        for metric in &metrics {
+            templates.validate_metric(metric)?;
+        }
+        // Aliases already handed out are kept, so they stay the same across
+        // rebirths.
+        let mut aliases = self.aliases.clone();
+        if let Some(aliases) = &mut aliases {
+            aliases.assign(None, &mut metrics)?;
+        }
+        let mut payload = get_node_birth_payload(self.clock.as_ref(), &self.sequences, bd_seq)?;
+        // Definitions passed among the metrics are not repeated.
+        let definitions: Vec<Payload_Metric> = templates
+            .definition_metrics(self.clock.as_ref())
+            .into_iter()
+            .filter(|d| {
+                !metrics
+                    .iter()
+                    .any(|m| is_definition(m) && m.get_name() == d.get_name())
+            })
+            .collect();
+        payload.mut_metrics().extend(definitions);
+        payload.mut_metrics().extend(metrics);
+        let mut messages = vec![self.encode(MessageType::NBIRTH, &payload)?];
+        for device_id in self.devices.keys() {
+            messages.push(self.device_birth(device_id)?);
+        }
+        for (device_id, metrics) in &self.queued {
+            if let Some(id) = device_id {
+                // Removed while the data was queued.
+                if !self.devices.contains_key(id) {
+                    continue;
+                }
+            }
+            let mut metrics = metrics.clone();
+            for metric in metrics.iter_mut() {
+                metric.set_is_historical(true);
+            }
+            messages.push(self.data_message(aliases.as_ref(), device_id.as_deref(), metrics)?);
+        }
+        self.templates = templates;
+        self.aliases = aliases;
+        self.state = NodeState::Born;
+        for device in self.devices.values_mut() {
+            device.born = true;
+        }
+        self.queued.clear();
+        Ok(messages)
+    }
+
+    fn hold_back(&mut self, device_id: Option<&str>, metrics: Vec<Payload_Metric>) {
+        let limit = match self.primary_host.as_ref().map(|h| h.offline_data) {
+            Some(OfflineData::Queue(limit)) => limit,
+            _ => return,
+        };
+        self.queued
+            .push_back((device_id.map(String::from), metrics));
+        while self.queued.len() > limit {
+            self.queued.pop_front();
+        }
+    }
+
+    // NDATA, or DDATA when `device_id` is given.
+    fn publish_data(
+        &self,
+        device_id: Option<&str>,
+        metrics: Vec<Payload_Metric>,
+    ) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        self.data_message(self.aliases.as_ref(), device_id, metrics)
+    }
+
+    fn data_message(
+        &self,
+        aliases: Option<&AliasRegistry>,
+        device_id: Option<&str>,
+        mut metrics: Vec<Payload_Metric>,
+    ) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        if let Some(aliases) = aliases {
+            aliases.compress(device_id, &mut metrics)?;
+        }
+        let mut payload = get_d_data_payload(self.clock.as_ref(), &self.sequences);
+        payload.set_metrics(RepeatedField::from_vec(metrics));
+        match device_id {
+            None => self.encode(MessageType::NDATA, &payload),
+            Some(device_id) => self.encode_device(MessageType::DDATA, device_id, &payload),
+        }
+    }
+
+    fn publish_device_birth(
+        &mut self,
+        device_id: &str,
+    ) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        let message = self.device_birth(device_id)?;
+        if let Some(device) = self.devices.get_mut(device_id) {
+            device.born = true;
+        }
+        Ok(message)
+    }
+
+    // The DBIRTH of a registered device, without marking it born.
+    fn device_birth(&self, device_id: &str) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        let device = self
+            .devices
+            .get(device_id)
+            .ok_or_else(|| SparkplugError::UnknownDevice(device_id.into()))?;
+        let mut payload = get_device_birth_payload(self.clock.as_ref(), &self.sequences);
+        payload.set_metrics(RepeatedField::from_vec(device.metrics.clone()));
+        self.encode_device(MessageType::DBIRTH, device_id, &payload)
+    }
+
+    fn encode_device(
+        &self,
+        message_type: MessageType,
+        device_id: &str,
+        payload: &Payload,
+    ) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        Ok((
+            self.device_topic(message_type, device_id),
+            payload.write_to_bytes().map_err(SparkplugError::Encode)?,
+        ))
+    }
+
+    fn encode(
+        &self,
+        message_type: MessageType,
+        payload: &Payload,
+    ) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        Ok((
+            self.topic(message_type),
+            payload.write_to_bytes().map_err(SparkplugError::Encode)?,
+        ))
+    }
+}
+
+// Only the value is taken from DDATA, the datatype, alias, properties and
+// metadata declared in DBIRTH are kept for the next birth.
+fn update_value(declared: &mut Payload_Metric, metric: &Payload_Metric) {
+    declared.value = metric.value.clone();
+    match metric.has_is_null() {
+        true => declared.set_is_null(metric.get_is_null()),
+        false => declared.clear_is_null(),
+    }
+    match metric.has_timestamp() {
+        true => declared.set_timestamp(metric.get_timestamp()),
+        false => declared.clear_timestamp(),
+    }
+}
+
+// True for an NCMD carrying `Node Control/Rebirth = true`.
+pub fn is_rebirth_request(payload: &Payload) -> bool {
+    payload
+        .get_metrics()
+        .iter()
+        .any(|m| m.get_name() == REBIRTH_METRIC && m.has_boolean_value() && m.get_boolean_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{state_birth, state_death, StateFormat};
+    use crate::{create_metric, FixedClock, MetricDataType};
+
+    fn node() -> EdgeNode {
+        EdgeNode::new("group", "node")
+            .unwrap()
+            .with_clock(Box::new(FixedClock::new(1000)))
+    }
+
+    fn metric(name: &str, value: i32) -> Payload_Metric {
+        create_metric(
+            &FixedClock::new(1000),
+            MetricDataType::Int32,
+            value,
+            name.into(),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn host(
+        node: &mut EdgeNode,
+        host_id: &str,
+        online: bool,
+        timestamp: u64,
+        format: StateFormat,
+    ) -> PrimaryHostEvent {
+        let (topic, bytes) = match online {
+            true => state_birth(host_id, timestamp, format),
+            false => state_death(host_id, timestamp, format),
+        }
+        .unwrap();
+        node.handle_state(&topic, &bytes).unwrap()
+    }
+
+    fn seq(message: &(Topic, Vec<u8>)) -> u64 {
+        Payload::parse_from_bytes(&message.1).unwrap().get_seq()
+    }
+
+    #[test]
+    fn connect_needs_a_disconnected_session() {
+        let mut node = node();
+        node.connect().unwrap();
+        assert!(matches!(
+            node.connect(),
+            Err(SparkplugError::AlreadyConnected)
+        ));
+        node.birth(vec![]).unwrap();
+        assert!(matches!(
+            node.connect(),
+            Err(SparkplugError::AlreadyConnected)
+        ));
+        assert_eq!(node.state(), NodeState::Born);
+        assert_eq!(node.bd_seq(), Some(0));
+
+        node.disconnected();
+        node.connect().unwrap();
+        assert_eq!(node.state(), NodeState::Connected);
+        assert_eq!(node.bd_seq(), Some(1));
+    }
+
+    #[test]
+    fn session_ordering() {
+        let mut node = node();
+        node.add_device("device", vec![metric("b", 1)]).unwrap();
+        assert!(matches!(
+            node.birth(vec![]),
+            Err(SparkplugError::NotConnected)
+        ));
+        assert!(matches!(node.data(vec![]), Err(SparkplugError::NotBorn)));
+        assert!(matches!(node.death(), Err(SparkplugError::NotConnected)));
+
+        let (will_topic, will) = node.connect().unwrap();
+        assert_eq!(will_topic, node.topic(MessageType::NDEATH));
+        assert!(matches!(
+            node.data(vec![metric("a", 1)]),
+            Err(SparkplugError::NotBorn)
+        ));
+        assert!(matches!(
+            node.device_data("device", vec![metric("b", 2)]),
+            Err(SparkplugError::NotBorn)
+        ));
+        assert!(matches!(node.rebirth(vec![]), Err(SparkplugError::NotBorn)));
+
+        let births = node.birth(vec![metric("a", 0)]).unwrap();
+        let topics: Vec<Topic> = births.iter().map(|(t, _)| t.clone()).collect();
+        assert_eq!(
+            topics,
+            [
+                node.topic(MessageType::NBIRTH),
+                node.device_topic(MessageType::DBIRTH, "device")
+            ]
+        );
+        assert_eq!(node.state(), NodeState::Born);
+        assert!(node.is_device_born("device"));
+        assert!(matches!(
+            node.birth(vec![]),
+            Err(SparkplugError::AlreadyBorn)
+        ));
+        node.data(vec![metric("a", 1)]).unwrap().unwrap();
+        node.device_data("device", vec![metric("b", 2)])
+            .unwrap()
+            .unwrap();
+
+        // The explicit NDEATH repeats the bdSeq of the will.
+        let (death_topic, death) = node.death().unwrap();
+        assert_eq!(death_topic, will_topic);
+        assert_eq!(death, will);
+        assert_eq!(node.state(), NodeState::Disconnected);
+        assert!(!node.is_device_born("device"));
+        assert!(matches!(
+            node.data(vec![metric("a", 1)]),
+            Err(SparkplugError::NotBorn)
+        ));
+    }
+
+    #[test]
+    fn seq_wraps_across_node_and_device_messages() {
+        let mut node = node();
+        node.add_device("device", vec![metric("b", 0)]).unwrap();
+        node.connect().unwrap();
+        let births = node.birth(vec![metric("a", 0)]).unwrap();
+        assert_eq!(births.iter().map(seq).collect::<Vec<_>>(), [0, 1]);
+        for expected in 2..=255 {
+            let message = node.data(vec![metric("a", 1)]).unwrap().unwrap();
+            assert_eq!(seq(&message), expected);
+        }
+        let message = node
+            .device_data("device", vec![metric("b", 1)])
+            .unwrap()
+            .unwrap();
+        assert_eq!(seq(&message), 0);
+        let message = node.data(vec![metric("a", 2)]).unwrap().unwrap();
+        assert_eq!(seq(&message), 1);
+
+        // A rebirth starts over from 0.
+        let births = node.rebirth(vec![metric("a", 3)]).unwrap();
+        assert_eq!(births.iter().map(seq).collect::<Vec<_>>(), [0, 1]);
+        let message = node
+            .device_data("device", vec![metric("b", 2)])
+            .unwrap()
+            .unwrap();
+        assert_eq!(seq(&message), 2);
+    }
+
+    #[test]
+    fn devices_come_and_go_while_born() {
+        let mut node = node();
+        node.connect().unwrap();
+        node.birth(vec![]).unwrap();
+
+        let (topic, bytes) = node
+            .add_device("late", vec![metric("b", 1)])
+            .unwrap()
+            .unwrap();
+        assert_eq!(topic, node.device_topic(MessageType::DBIRTH, "late"));
+        assert_eq!(seq(&(topic, bytes.clone())), 1);
+        let payload = Payload::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(payload.get_metrics()[0].get_name(), "b");
+        assert!(node.is_device_born("late"));
+        assert!(matches!(
+            node.add_device("late", vec![]),
+            Err(SparkplugError::DuplicateDevice(_))
+        ));
+        node.device_data("late", vec![metric("b", 2)])
+            .unwrap()
+            .unwrap();
+
+        let (topic, bytes) = node.remove_device("late").unwrap().unwrap();
+        assert_eq!(topic, node.device_topic(MessageType::DDEATH, "late"));
+        assert_eq!(seq(&(topic, bytes)), 3);
+        assert_eq!(node.device_ids().count(), 0);
+        assert!(matches!(
+            node.device_data("late", vec![]),
+            Err(SparkplugError::UnknownDevice(_))
+        ));
+        assert!(matches!(
+            node.remove_device("late"),
+            Err(SparkplugError::UnknownDevice(_))
+        ));
+
+        // The next rebirth has no DBIRTH for the removed device.
+        assert_eq!(node.rebirth(vec![]).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn births_wait_for_the_primary_host() {
+        let mut node = node().with_primary_host("scada", OfflineData::Drop);
+        node.connect().unwrap();
+        assert!(!node.may_publish());
+        assert!(matches!(
+            node.birth(vec![]),
+            Err(SparkplugError::PrimaryHostOffline)
+        ));
+        assert_eq!(
+            host(&mut node, "other", true, 10, StateFormat::Json),
+            PrimaryHostEvent::Ignored
+        );
+        assert!(!node.may_publish());
+        assert_eq!(
+            host(&mut node, "scada", true, 10, StateFormat::Json),
+            PrimaryHostEvent::Online
+        );
+        node.birth(vec![]).unwrap();
+
+        // An older STATE is stale, a newer ONLINE is a new host session.
+        assert_eq!(
+            host(&mut node, "scada", false, 5, StateFormat::Json),
+            PrimaryHostEvent::Ignored
+        );
+        assert_eq!(
+            host(&mut node, "scada", true, 10, StateFormat::Json),
+            PrimaryHostEvent::Ignored
+        );
+        assert!(matches!(
+            host(&mut node, "scada", true, 20, StateFormat::Json),
+            PrimaryHostEvent::Restarted(_, _)
+        ));
+        assert_eq!(node.state(), NodeState::Disconnected);
+    }
+
+    #[test]
+    fn repeated_legacy_online_is_not_a_restart() {
+        let mut node = node().with_primary_host("scada", OfflineData::Drop);
+        assert_eq!(
+            host(&mut node, "scada", true, 0, StateFormat::Legacy),
+            PrimaryHostEvent::Online
+        );
+        node.connect().unwrap();
+        node.birth(vec![]).unwrap();
+        assert_eq!(
+            host(&mut node, "scada", true, 0, StateFormat::Legacy),
+            PrimaryHostEvent::Ignored
+        );
+        assert_eq!(node.state(), NodeState::Born);
+
+        match host(&mut node, "scada", false, 0, StateFormat::Legacy) {
+            PrimaryHostEvent::Offline(Some((topic, _))) => {
+                assert_eq!(topic, node.topic(MessageType::NDEATH))
+            }
+            event => panic!("unexpected {:?}", event),
+        }
+        assert_eq!(
+            host(&mut node, "scada", false, 0, StateFormat::Legacy),
+            PrimaryHostEvent::Ignored
+        );
+        assert_eq!(
+            host(&mut node, "scada", true, 0, StateFormat::Legacy),
+            PrimaryHostEvent::Online
+        );
+    }
+
+    #[test]
+    fn offline_data_is_dropped() {
+        let mut node = node().with_primary_host("scada", OfflineData::Drop);
+        node.add_device("device", vec![metric("b", 0)]).unwrap();
+        host(&mut node, "scada", true, 10, StateFormat::Json);
+        node.connect().unwrap();
+        node.birth(vec![metric("a", 0)]).unwrap();
+        host(&mut node, "scada", false, 20, StateFormat::Json);
+
+        assert_eq!(node.data(vec![metric("a", 1)]).unwrap(), None);
+        assert_eq!(
+            node.device_data("device", vec![metric("b", 1)]).unwrap(),
+            None
+        );
+        assert_eq!(node.queued(), 0);
+        // The latest device value is still kept for the next DBIRTH.
+        assert_eq!(node.device_metrics("device").unwrap()[0].get_int_value(), 1);
+
+        host(&mut node, "scada", true, 30, StateFormat::Json);
+        node.connect().unwrap();
+        assert_eq!(node.birth(vec![metric("a", 0)]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn offline_data_is_queued_as_historical() {
+        let mut node = node().with_primary_host("scada", OfflineData::Queue(2));
+        node.add_device("device", vec![metric("b", 0)]).unwrap();
+        for value in 1..=2 {
+            node.data(vec![metric("a", value)]).unwrap();
+        }
+        node.device_data("device", vec![metric("b", 3)]).unwrap();
+        assert_eq!(node.queued(), 2);
+
+        host(&mut node, "scada", true, 10, StateFormat::Json);
+        node.connect().unwrap();
+        let messages = node.birth(vec![metric("a", 0)]).unwrap();
+        assert_eq!(node.queued(), 0);
+        let topics: Vec<Topic> = messages.iter().map(|(t, _)| t.clone()).collect();
+        assert_eq!(
+            topics,
+            [
+                node.topic(MessageType::NBIRTH),
+                node.device_topic(MessageType::DBIRTH, "device"),
+                node.topic(MessageType::NDATA),
+                node.device_topic(MessageType::DDATA, "device"),
+            ]
+        );
+        // The oldest message was dropped to stay within the limit.
+        for (message, value) in messages[2..].iter().zip([2, 3]) {
+            let payload = Payload::parse_from_bytes(&message.1).unwrap();
+            let metric = &payload.get_metrics()[0];
+            assert!(metric.get_is_historical());
+            assert_eq!(metric.get_int_value(), value);
+        }
+        assert_eq!(messages.iter().map(seq).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn failed_birth_keeps_the_registries() {
+        let mut node = node()
+            .with_aliases()
+            .with_primary_host("scada", OfflineData::Queue(10));
+        // Never declared in the birth, so it has no alias to be sent with.
+        node.data(vec![metric("undeclared", 1)]).unwrap();
+        host(&mut node, "scada", true, 10, StateFormat::Json);
+        node.connect().unwrap();
+        assert!(matches!(
+            node.birth(vec![metric("a", 0)]),
+            Err(SparkplugError::UnknownMetric(_))
+        ));
+        assert_eq!(node.aliases().unwrap().alias(None, "a"), None);
+        assert_eq!(node.state(), NodeState::Connected);
+        assert_eq!(node.queued(), 1);
+    }
+}
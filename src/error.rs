@@ -0,0 +1,193 @@
+use crate::{MetricDataType, TopicError};
+use protobuf::ProtobufError;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum SparkplugError {
+    // A string could not be parsed as the datatype of the metric.
+    Parse {
+        metric: String,
+        data_type: MetricDataType,
+        value: String,
+    },
+    // The datatype cannot be used where it was given, e.g. a PropertySet metric.
+    UnsupportedType {
+        metric: String,
+        data_type: MetricDataType,
+    },
+    // A `datatype` field that is not a value of the Sparkplug DataType enum.
+    UnknownDataType {
+        metric: String,
+        datatype: u32,
+    },
+    // The value does not belong to the declared datatype. `found` is the
+    // datatype of the value given, or the protobuf field that was populated.
+    TypeMismatch {
+        metric: String,
+        data_type: MetricDataType,
+        found: String,
+    },
+    // A BIRTH metric or template definition member without a datatype.
+    MissingDataType {
+        metric: String,
+    },
+    // Neither `is_null` nor a value is set.
+    MissingValue {
+        metric: String,
+        data_type: MetricDataType,
+    },
+    // The value is in the right field but out of range for the datatype, or
+    // the array bytes are not a valid packing.
+    InvalidValue {
+        metric: String,
+        data_type: MetricDataType,
+    },
+    // A DataSet whose columns, types and rows disagree.
+    InvalidDataSet {
+        metric: String,
+        reason: String,
+    },
+    // A PropertySet whose keys and values do not pair up.
+    InvalidProperties(String),
+    // A template instance whose template_ref is not a known definition.
+    UnknownTemplate(String),
+    // A template definition or instance that breaks the template rules.
+    InvalidTemplate {
+        template: String,
+        reason: String,
+    },
+    InvalidTopic(TopicError),
+    // STATE messages do not carry a protobuf payload, see `state::decode_state`.
+    StateMessage,
+    // A STATE payload that is neither the 3.0 JSON nor ONLINE/OFFLINE.
+    InvalidState(String),
+    // JSON that does not have the shape of a payload, see `json::from_json`.
+    InvalidJson(String),
+    // An edge node configuration that is not well formed, see `config`.
+    InvalidConfig(String),
+    Encode(ProtobufError),
+    Decode(ProtobufError),
+
+    // Session and sequence errors.
+    // NBIRTH was requested before the NDEATH will was built.
+    NotConnected,
+    // A new session was requested while the current one is still up, call
+    // `disconnected` or `death` first.
+    AlreadyConnected,
+    // NBIRTH was requested twice in the same session, use a rebirth.
+    AlreadyBorn,
+    // NDATA was requested before NBIRTH.
+    NotBorn,
+    // The device id has not been added to the edge node.
+    UnknownDevice(String),
+    // DDATA was requested before the DBIRTH of the device.
+    DeviceNotBorn(String),
+    // A device was added twice with the same id.
+    DuplicateDevice(String),
+    // NBIRTH was requested while the primary host is not online.
+    PrimaryHostOffline,
+
+    // Alias errors.
+    // Two metrics of the same edge node were given the same alias.
+    DuplicateAlias {
+        alias: u64,
+        metric: String,
+    },
+    // A DATA metric whose name was not declared in the birth.
+    UnknownMetric(String),
+}
+
+impl fmt::Display for SparkplugError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SparkplugError::Parse {
+                metric,
+                data_type,
+                value,
+            } => write!(
+                f,
+                "metric `{}`: cannot parse `{}` as {:?}",
+                metric, value, data_type
+            ),
+            SparkplugError::UnsupportedType { metric, data_type } => {
+                write!(
+                    f,
+                    "metric `{}`: unsupported datatype {:?}",
+                    metric, data_type
+                )
+            }
+            SparkplugError::UnknownDataType { metric, datatype } => {
+                write!(f, "metric `{}`: unknown datatype {}", metric, datatype)
+            }
+            SparkplugError::TypeMismatch {
+                metric,
+                data_type,
+                found,
+            } => write!(
+                f,
+                "metric `{}`: declared {:?} but got {}",
+                metric, data_type, found
+            ),
+            SparkplugError::MissingDataType { metric } => {
+                write!(f, "metric `{}`: no datatype", metric)
+            }
+            SparkplugError::MissingValue { metric, data_type } => {
+                write!(f, "metric `{}`: no {:?} value", metric, data_type)
+            }
+            SparkplugError::InvalidValue { metric, data_type } => {
+                write!(f, "metric `{}`: invalid {:?} value", metric, data_type)
+            }
+            SparkplugError::InvalidDataSet { metric, reason } => {
+                write!(f, "metric `{}`: invalid DataSet: {}", metric, reason)
+            }
+            SparkplugError::InvalidProperties(reason) => {
+                write!(f, "invalid PropertySet: {}", reason)
+            }
+            SparkplugError::UnknownTemplate(t) => write!(f, "unknown template `{}`", t),
+            SparkplugError::InvalidTemplate { template, reason } => {
+                write!(f, "template `{}`: {}", template, reason)
+            }
+            SparkplugError::InvalidTopic(e) => e.fmt(f),
+            SparkplugError::StateMessage => f.write_str("STATE messages are not protobuf payloads"),
+            SparkplugError::InvalidState(reason) => write!(f, "invalid STATE message: {}", reason),
+            SparkplugError::InvalidJson(reason) => write!(f, "invalid payload JSON: {}", reason),
+            SparkplugError::InvalidConfig(reason) => {
+                write!(f, "invalid edge node configuration: {}", reason)
+            }
+            SparkplugError::Encode(e) => write!(f, "payload encoding failed: {}", e),
+            SparkplugError::Decode(e) => write!(f, "payload decoding failed: {}", e),
+            SparkplugError::NotConnected => f.write_str("no NDEATH registered for this session"),
+            SparkplugError::AlreadyConnected => f.write_str("MQTT session already started"),
+            SparkplugError::AlreadyBorn => f.write_str("NBIRTH already published"),
+            SparkplugError::NotBorn => f.write_str("NBIRTH has not been published"),
+            SparkplugError::UnknownDevice(d) => write!(f, "unknown device `{}`", d),
+            SparkplugError::DeviceNotBorn(d) => {
+                write!(f, "DBIRTH of `{}` has not been published", d)
+            }
+            SparkplugError::DuplicateDevice(d) => write!(f, "device `{}` already added", d),
+            SparkplugError::PrimaryHostOffline => f.write_str("primary host is not online"),
+            SparkplugError::DuplicateAlias { alias, metric } => {
+                write!(f, "metric `{}`: alias {} already in use", metric, alias)
+            }
+            SparkplugError::UnknownMetric(m) => {
+                write!(f, "metric `{}` was not declared in the birth", m)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SparkplugError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SparkplugError::InvalidTopic(e) => Some(e),
+            SparkplugError::Encode(e) | SparkplugError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TopicError> for SparkplugError {
+    fn from(e: TopicError) -> Self {
+        SparkplugError::InvalidTopic(e)
+    }
+}
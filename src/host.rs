@@ -0,0 +1,574 @@
+use crate::alias::AliasRegistry;
+use crate::decode::{decode_payload, decode_payload_with_types, Message, Metric};
+use crate::sparkplug_b::{Payload, Payload_Metric};
+use crate::{
+    create_metric, Clock, MessageType, MetricDataType, MetricValue, SparkplugError, SystemClock,
+    Topic,
+};
+use protobuf::{Message as _, RepeatedField};
+use std::collections::HashMap;
+
+// ######################################################################
+// # Host application
+// ######################################################################
+// Follows the edge nodes seen on the broker. Every (topic, payload) pair
+// received is passed to `ingest`, which keeps for each edge node
+//
+//   - the bdSeq of its current NBIRTH, so an NDEATH left over from an older
+//     session is recognised and ignored
+//   - the seq the next message has to carry
+//   - the metrics it and its devices declared in their births, and the
+//     aliases they declared, so metrics sent by alias only get their name
+//   - whether it and each of its devices are online
+//
+// and reports what happened as `HostEvent`s. When the state of a node can no
+// longer be trusted (a seq gap, data before a birth) a `RebirthRequest` with
+// the NCMD to publish is returned, at most once until the next NBIRTH.
+// ######################################################################
+
+pub const REBIRTH_METRIC: &str = "Node Control/Rebirth";
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum HostEvent {
+    NodeOnline {
+        group_id: String,
+        edge_node_id: String,
+    },
+    NodeOffline {
+        group_id: String,
+        edge_node_id: String,
+    },
+    DeviceOnline {
+        group_id: String,
+        edge_node_id: String,
+        device_id: String,
+    },
+    DeviceOffline {
+        group_id: String,
+        edge_node_id: String,
+        device_id: String,
+    },
+    // NDATA or DDATA from an online node or device.
+    Data(Message),
+    // The seq of a message did not follow the previous one.
+    SequenceGap {
+        group_id: String,
+        edge_node_id: String,
+        expected: u64,
+        received: u64,
+    },
+    // An NDEATH whose bdSeq does not match the current NBIRTH. It belongs to
+    // an earlier session and is ignored.
+    StaleDeath {
+        group_id: String,
+        edge_node_id: String,
+        bd_seq: Option<u64>,
+    },
+    // A message from a node or device that is not online.
+    UnexpectedMessage(Topic),
+    // The NCMD to publish to get a fresh NBIRTH.
+    RebirthRequest(Topic, Vec<u8>),
+}
+
+#[derive(Debug, Default)]
+struct NodeRecord {
+    online: bool,
+    bd_seq: Option<u64>,
+    expected_seq: u64,
+    rebirth_pending: bool,
+    metrics: Vec<Metric>,
+    aliases: AliasRegistry,
+    devices: HashMap<String, DeviceRecord>,
+}
+
+#[derive(Debug, Default)]
+struct DeviceRecord {
+    online: bool,
+    metrics: Vec<Metric>,
+}
+
+pub struct HostApplication {
+    nodes: HashMap<(String, String), NodeRecord>,
+    clock: Box<dyn Clock>,
+}
+
+impl Default for HostApplication {
+    fn default() -> Self {
+        HostApplication::new()
+    }
+}
+
+impl HostApplication {
+    pub fn new() -> HostApplication {
+        HostApplication {
+            nodes: HashMap::new(),
+            clock: Box::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Box<dyn Clock>) -> HostApplication {
+        self.clock = clock;
+        self
+    }
+
+    pub fn is_node_online(&self, group_id: &str, edge_node_id: &str) -> bool {
+        self.node(group_id, edge_node_id).is_some_and(|n| n.online)
+    }
+
+    pub fn is_device_online(&self, group_id: &str, edge_node_id: &str, device_id: &str) -> bool {
+        self.node(group_id, edge_node_id)
+            .and_then(|n| n.devices.get(device_id))
+            .is_some_and(|d| d.online)
+    }
+
+    // bdSeq of the last NBIRTH of the node.
+    pub fn node_bd_seq(&self, group_id: &str, edge_node_id: &str) -> Option<u64> {
+        self.node(group_id, edge_node_id).and_then(|n| n.bd_seq)
+    }
+
+    // The metrics declared in the last NBIRTH of the node.
+    pub fn node_metrics(&self, group_id: &str, edge_node_id: &str) -> Option<&[Metric]> {
+        self.node(group_id, edge_node_id)
+            .map(|n| n.metrics.as_slice())
+    }
+
+    // The metrics declared in the last DBIRTH of the device.
+    pub fn device_metrics(
+        &self,
+        group_id: &str,
+        edge_node_id: &str,
+        device_id: &str,
+    ) -> Option<&[Metric]> {
+        self.node(group_id, edge_node_id)
+            .and_then(|n| n.devices.get(device_id))
+            .map(|d| d.metrics.as_slice())
+    }
+
+    // The NCMD asking an edge node to publish its births again.
+    pub fn rebirth_request(
+        &self,
+        group_id: &str,
+        edge_node_id: &str,
+    ) -> Result<(Topic, Vec<u8>), SparkplugError> {
+        let topic = Topic::node(group_id, MessageType::NCMD, edge_node_id)?;
+        let mut payload = Payload::new();
+        payload.set_timestamp(self.clock.now_millis());
+        payload.set_metrics(RepeatedField::from_vec(vec![create_metric(
+            self.clock.as_ref(),
+            MetricDataType::Boolean,
+            true,
+            REBIRTH_METRIC.into(),
+            None,
+            None,
+        )?]));
+        let bytes = payload.write_to_bytes().map_err(SparkplugError::Encode)?;
+        Ok((topic, bytes))
+    }
+
+    pub fn ingest(
+        &mut self,
+        topic: &Topic,
+        bytes: &[u8],
+    ) -> Result<Vec<HostEvent>, SparkplugError> {
+        let (group_id, edge_node_id) = match topic {
+            Topic::Edge {
+                group_id,
+                edge_node_id,
+                ..
+            } => (group_id.clone(), edge_node_id.clone()),
+            Topic::State { .. } => return Ok(vec![]),
+        };
+        let message_type = topic.message_type();
+        if matches!(message_type, MessageType::NCMD | MessageType::DCMD) {
+            return Ok(vec![]);
+        }
+        let payload = Payload::parse_from_bytes(bytes).map_err(SparkplugError::Decode)?;
+        let mut events = vec![];
+        match message_type {
+            MessageType::NBIRTH => {
+                let message = decode_payload(topic.clone(), &payload)?;
+                let mut aliases = AliasRegistry::new();
+                aliases.register(None, &message.metrics)?;
+                let node = self
+                    .nodes
+                    .entry((group_id.clone(), edge_node_id.clone()))
+                    .or_default();
+                take_devices_offline(node, &group_id, &edge_node_id, &mut events);
+                node.online = true;
+                node.bd_seq = bd_seq(&message.metrics);
+                node.expected_seq = next_seq(message.seq);
+                node.rebirth_pending = false;
+                node.metrics = message.metrics;
+                node.aliases = aliases;
+                events.push(HostEvent::NodeOnline {
+                    group_id,
+                    edge_node_id,
+                });
+            }
+            MessageType::NDEATH => {
+                let message = decode_payload(topic.clone(), &payload)?;
+                let death_bd_seq = bd_seq(&message.metrics);
+                match self
+                    .nodes
+                    .get_mut(&(group_id.clone(), edge_node_id.clone()))
+                {
+                    Some(node) if node.online && node.bd_seq == death_bd_seq => {
+                        take_devices_offline(node, &group_id, &edge_node_id, &mut events);
+                        node.online = false;
+                        events.push(HostEvent::NodeOffline {
+                            group_id,
+                            edge_node_id,
+                        });
+                    }
+                    _ => events.push(HostEvent::StaleDeath {
+                        group_id,
+                        edge_node_id,
+                        bd_seq: death_bd_seq,
+                    }),
+                }
+            }
+            _ => {
+                self.ingest_sequenced(topic, &payload, &group_id, &edge_node_id, &mut events)?;
+            }
+        }
+        Ok(events)
+    }
+
+    // NDATA, DBIRTH, DDATA and DDEATH, which all carry a seq and need the node
+    // to be online.
+    fn ingest_sequenced(
+        &mut self,
+        topic: &Topic,
+        payload: &Payload,
+        group_id: &str,
+        edge_node_id: &str,
+        events: &mut Vec<HostEvent>,
+    ) -> Result<(), SparkplugError> {
+        let node = match self.nodes.get_mut(&(group_id.into(), edge_node_id.into())) {
+            Some(node) if node.online => node,
+            _ => {
+                events.push(HostEvent::UnexpectedMessage(topic.clone()));
+                return self.request_rebirth(group_id, edge_node_id, events);
+            }
+        };
+
+        let received = payload.get_seq();
+        let mut out_of_sequence = false;
+        if received != node.expected_seq {
+            events.push(HostEvent::SequenceGap {
+                group_id: group_id.into(),
+                edge_node_id: edge_node_id.into(),
+                expected: node.expected_seq,
+                received,
+            });
+            out_of_sequence = true;
+        }
+        node.expected_seq = next_seq(Some(received));
+
+        let device_id = topic.device_id().map(String::from);
+        let mut unexpected = false;
+        match (topic.message_type(), device_id) {
+            (MessageType::NDATA, _) => {
+                let mut message = decode_payload_with_types(topic.clone(), payload, |m| {
+                    declared_type(&node.metrics, m)
+                })?;
+                node.aliases.resolve(None, &mut message.metrics);
+                events.push(HostEvent::Data(message));
+            }
+            (MessageType::DBIRTH, Some(device_id)) => {
+                let message = decode_payload(topic.clone(), payload)?;
+                // A rejected DBIRTH leaves the aliases of the last one in place.
+                let mut aliases = node.aliases.clone();
+                aliases.clear_device(&device_id);
+                aliases.register(Some(&device_id), &message.metrics)?;
+                node.aliases = aliases;
+                node.devices.insert(
+                    device_id.clone(),
+                    DeviceRecord {
+                        online: true,
+                        metrics: message.metrics,
+                    },
+                );
+                events.push(HostEvent::DeviceOnline {
+                    group_id: group_id.into(),
+                    edge_node_id: edge_node_id.into(),
+                    device_id,
+                });
+            }
+            (MessageType::DDATA, Some(device_id)) => match node.devices.get(&device_id) {
+                Some(device) if device.online => {
+                    let mut message = decode_payload_with_types(topic.clone(), payload, |m| {
+                        declared_type(&device.metrics, m)
+                    })?;
+                    node.aliases.resolve(Some(&device_id), &mut message.metrics);
+                    events.push(HostEvent::Data(message));
+                }
+                _ => {
+                    events.push(HostEvent::UnexpectedMessage(topic.clone()));
+                    unexpected = true;
+                }
+            },
+            (MessageType::DDEATH, Some(device_id)) => match node.devices.get_mut(&device_id) {
+                Some(device) if device.online => {
+                    device.online = false;
+                    events.push(HostEvent::DeviceOffline {
+                        group_id: group_id.into(),
+                        edge_node_id: edge_node_id.into(),
+                        device_id,
+                    });
+                }
+                _ => events.push(HostEvent::UnexpectedMessage(topic.clone())),
+            },
+            _ => events.push(HostEvent::UnexpectedMessage(topic.clone())),
+        }
+
+        if out_of_sequence || unexpected {
+            self.request_rebirth(group_id, edge_node_id, events)?;
+        }
+        Ok(())
+    }
+
+    fn request_rebirth(
+        &mut self,
+        group_id: &str,
+        edge_node_id: &str,
+        events: &mut Vec<HostEvent>,
+    ) -> Result<(), SparkplugError> {
+        let node = self
+            .nodes
+            .entry((group_id.into(), edge_node_id.into()))
+            .or_default();
+        if node.rebirth_pending {
+            return Ok(());
+        }
+        node.rebirth_pending = true;
+        let (topic, bytes) = self.rebirth_request(group_id, edge_node_id)?;
+        events.push(HostEvent::RebirthRequest(topic, bytes));
+        Ok(())
+    }
+
+    fn node(&self, group_id: &str, edge_node_id: &str) -> Option<&NodeRecord> {
+        self.nodes.get(&(group_id.into(), edge_node_id.into()))
+    }
+}
+
+fn take_devices_offline(
+    node: &mut NodeRecord,
+    group_id: &str,
+    edge_node_id: &str,
+    events: &mut Vec<HostEvent>,
+) {
+    for (device_id, device) in node.devices.iter_mut() {
+        if device.online {
+            device.online = false;
+            events.push(HostEvent::DeviceOffline {
+                group_id: group_id.into(),
+                edge_node_id: edge_node_id.into(),
+                device_id: device_id.clone(),
+            });
+        }
+    }
+}
+
+fn bd_seq(metrics: &[Metric]) -> Option<u64> {
+    metrics
+        .iter()
+        .find(|m| m.name.as_deref() == Some("bdSeq"))
+        .and_then(|m| match m.value {
+            Some(MetricValue::Int64(v)) => u64::try_from(v).ok(),
+            Some(MetricValue::UInt64(v)) => Some(v),
+            _ => None,
+        })
+}
+
+fn next_seq(seq: Option<u64>) -> u64 {
+    (seq.unwrap_or(0) + 1) % 256
+}
+
+// The datatype a birth declared for the metric, matched by name or alias.
+fn declared_type(birth: &[Metric], metric: &Payload_Metric) -> Option<MetricDataType> {
+    birth
+        .iter()
+        .find(|b| {
+            (metric.has_name() && b.name.as_deref() == Some(metric.get_name()))
+                || (metric.has_alias() && b.alias == Some(metric.get_alias()))
+        })
+        .map(|b| b.data_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_node_birth_payload, node_death_payload, FixedClock, SequenceState};
+
+    fn ingest(host: &mut HostApplication, topic: &str, payload: &Payload) -> Vec<HostEvent> {
+        let bytes = payload.write_to_bytes().unwrap();
+        host.ingest(&topic.parse().unwrap(), &bytes).unwrap()
+    }
+
+    fn birth(bd_seq: u64) -> Payload {
+        get_node_birth_payload(&FixedClock::new(1), &SequenceState::new(), bd_seq).unwrap()
+    }
+
+    fn data(seq: u64, value: i32) -> Payload {
+        let clock = FixedClock::new(2);
+        let mut payload = Payload::new();
+        payload.set_timestamp(2);
+        payload.set_seq(seq);
+        payload.mut_metrics().push(
+            create_metric(&clock, MetricDataType::Int32, value, "m".into(), None, None).unwrap(),
+        );
+        payload
+    }
+
+    fn is_rebirth_request(event: &HostEvent) -> bool {
+        matches!(event, HostEvent::RebirthRequest(topic, _) if topic.to_string() == "spBv1.0/G/NCMD/E")
+    }
+
+    #[test]
+    fn birth_then_data_in_sequence() {
+        let mut host = HostApplication::new().with_clock(Box::new(FixedClock::new(3)));
+        let events = ingest(&mut host, "spBv1.0/G/NBIRTH/E", &birth(7));
+        assert_eq!(
+            events,
+            vec![HostEvent::NodeOnline {
+                group_id: "G".into(),
+                edge_node_id: "E".into()
+            }]
+        );
+        assert_eq!(host.node_bd_seq("G", "E"), Some(7));
+        let events = ingest(&mut host, "spBv1.0/G/NDATA/E", &data(1, 5));
+        assert!(
+            matches!(&events[..], [HostEvent::Data(m)] if m.metrics[0].value == Some(MetricValue::Int32(5)))
+        );
+    }
+
+    #[test]
+    fn seq_wraps_without_gap() {
+        let mut host = HostApplication::new();
+        ingest(&mut host, "spBv1.0/G/NBIRTH/E", &birth(0));
+        for seq in (1..=255).chain(0..=1) {
+            let events = ingest(&mut host, "spBv1.0/G/NDATA/E", &data(seq, 1));
+            assert!(matches!(&events[..], [HostEvent::Data(_)]), "seq {}", seq);
+        }
+    }
+
+    #[test]
+    fn seq_gap_requests_one_rebirth() {
+        let mut host = HostApplication::new();
+        ingest(&mut host, "spBv1.0/G/NBIRTH/E", &birth(0));
+        let events = ingest(&mut host, "spBv1.0/G/NDATA/E", &data(3, 1));
+        assert_eq!(
+            events[0],
+            HostEvent::SequenceGap {
+                group_id: "G".into(),
+                edge_node_id: "E".into(),
+                expected: 1,
+                received: 3,
+            }
+        );
+        assert!(matches!(events[1], HostEvent::Data(_)));
+        assert!(is_rebirth_request(&events[2]));
+
+        // Already requested, the next gap is only reported.
+        let events = ingest(&mut host, "spBv1.0/G/NDATA/E", &data(9, 1));
+        assert!(!events.iter().any(is_rebirth_request));
+
+        // The rebirth resets the expected seq and allows a new request.
+        ingest(&mut host, "spBv1.0/G/NBIRTH/E", &birth(0));
+        let events = ingest(&mut host, "spBv1.0/G/NDATA/E", &data(2, 1));
+        assert!(events.iter().any(is_rebirth_request));
+    }
+
+    #[test]
+    fn data_before_birth_requests_rebirth() {
+        let mut host = HostApplication::new();
+        let events = ingest(&mut host, "spBv1.0/G/NDATA/E", &data(1, 1));
+        assert_eq!(
+            events[0],
+            HostEvent::UnexpectedMessage("spBv1.0/G/NDATA/E".parse().unwrap())
+        );
+        assert!(is_rebirth_request(&events[1]));
+    }
+
+    #[test]
+    fn death_of_an_older_session_is_stale() {
+        let mut host = HostApplication::new();
+        let clock = FixedClock::new(1);
+        ingest(&mut host, "spBv1.0/G/NBIRTH/E", &birth(4));
+
+        let stale = node_death_payload(&clock, 3).unwrap();
+        let events = ingest(&mut host, "spBv1.0/G/NDEATH/E", &stale);
+        assert_eq!(
+            events,
+            vec![HostEvent::StaleDeath {
+                group_id: "G".into(),
+                edge_node_id: "E".into(),
+                bd_seq: Some(3),
+            }]
+        );
+        assert!(host.is_node_online("G", "E"));
+
+        let death = node_death_payload(&clock, 4).unwrap();
+        let events = ingest(&mut host, "spBv1.0/G/NDEATH/E", &death);
+        assert_eq!(
+            events,
+            vec![HostEvent::NodeOffline {
+                group_id: "G".into(),
+                edge_node_id: "E".into(),
+            }]
+        );
+        assert!(!host.is_node_online("G", "E"));
+    }
+
+    #[test]
+    fn rejected_device_birth_keeps_the_aliases() {
+        let clock = FixedClock::new(2);
+        let metric = |name: &str, alias: u64| {
+            create_metric(
+                &clock,
+                MetricDataType::Int32,
+                1,
+                name.into(),
+                Some(alias),
+                None,
+            )
+            .unwrap()
+        };
+        let device_birth = |seq: u64, metrics: Vec<Payload_Metric>| {
+            let mut payload = Payload::new();
+            payload.set_timestamp(2);
+            payload.set_seq(seq);
+            payload.set_metrics(RepeatedField::from_vec(metrics));
+            payload
+        };
+        let mut host = HostApplication::new();
+        ingest(&mut host, "spBv1.0/G/NBIRTH/E", &birth(0));
+        ingest(
+            &mut host,
+            "spBv1.0/G/DBIRTH/E/D",
+            &device_birth(1, vec![metric("x", 5)]),
+        );
+
+        let bytes = device_birth(2, vec![metric("y", 6), metric("z", 6)])
+            .write_to_bytes()
+            .unwrap();
+        assert!(matches!(
+            host.ingest(&"spBv1.0/G/DBIRTH/E/D".parse().unwrap(), &bytes),
+            Err(SparkplugError::DuplicateAlias { alias: 6, .. })
+        ));
+        assert_eq!(
+            host.device_metrics("G", "E", "D").unwrap()[0]
+                .name
+                .as_deref(),
+            Some("x")
+        );
+
+        let mut data = device_birth(3, vec![metric("x", 5)]);
+        data.mut_metrics()[0].clear_name();
+        let events = ingest(&mut host, "spBv1.0/G/DDATA/E/D", &data);
+        assert!(
+            matches!(&events[..], [HostEvent::Data(m)] if m.metrics[0].name.as_deref() == Some("x"))
+        );
+    }
+}
@@ -0,0 +1,679 @@
+use crate::dataset::DataSetReader;
+use crate::decode::{decode_metric_as, is_scalar_type, metric_data_type, metric_label};
+use crate::parse::{array_value, dataset_value, json_text, parse_scalar};
+use crate::properties::{PropertySet, PropertyValue};
+use crate::sparkplug_b::{
+    Payload, Payload_DataSet, Payload_MetaData, Payload_Metric, Payload_Template,
+    Payload_Template_Parameter,
+};
+use crate::template::{create_parameter, declare_parameter, read_parameter};
+use crate::{set_metric_value, MetricDataType, MetricValue, SparkplugError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::{Map, Number, Value};
+use std::str::FromStr;
+
+// ######################################################################
+// # JSON payloads
+// ######################################################################
+// Payloads in the JSON shape of Eclipse Tahu's `SparkplugBPayload`, to log,
+// diff or write them by hand:
+//
+//   {"timestamp": 1668114759000,
+//    "metrics": [{"name": "Node Control/Rebirth", "alias": 1,
+//                 "timestamp": 1668114759000, "dataType": "Boolean",
+//                 "value": false}],
+//    "seq": 0}
+//
+// Fields the protobuf payload does not set are left out. A null metric has
+// `"value": null`, a metric without a value has no `value` at all. A metric
+// with a value but no datatype is written with the one its value field
+// implies (int_value as UInt32, ...), as Tahu always names one and the value
+// cannot be read back without it. So `from_json` gives back the payload
+// `to_json` was given, except that such metrics come back with that datatype
+// set.
+//
+// Values by datatype:
+//
+//   integers, Boolean,   JSON numbers, booleans and strings
+//   String, Text, UUID
+//   Float, Double        numbers, or "NaN", "Infinity", "-Infinity"
+//   DateTime             epoch milliseconds, RFC 3339 strings are read too
+//   Bytes, File          base64 strings
+//   arrays               JSON arrays of the element values
+//   DataSet              {"numberOfColumns": 2, "columnNames": ["id", "label"],
+//                         "types": ["Int32", "String"],
+//                         "rows": [[1, "a"], [2, null]]}
+//   Template             {"version": "1.0", "templateRef": "Motor",
+//                         "isDefinition": false, "metrics": [...],
+//                         "parameters": [{"name": "rpm", "type": "Int32",
+//                                         "value": 3000}]}
+//
+// Properties are an object of `{"type": ..., "value": ...}` by key. The value
+// of a PropertySet is such an object, of a PropertySetList an array of them.
+// ######################################################################
+
+pub fn to_json(payload: &Payload) -> Result<Value, SparkplugError> {
+    let mut json = Map::new();
+    if payload.has_timestamp() {
+        json.insert("timestamp".into(), payload.get_timestamp().into());
+    }
+    let metrics = payload
+        .get_metrics()
+        .iter()
+        .map(metric_to_json)
+        .collect::<Result<Vec<Value>, SparkplugError>>()?;
+    json.insert("metrics".into(), Value::Array(metrics));
+    if payload.has_seq() {
+        json.insert("seq".into(), payload.get_seq().into());
+    }
+    if payload.has_uuid() {
+        json.insert("uuid".into(), payload.get_uuid().into());
+    }
+    if payload.has_body() {
+        json.insert("body".into(), STANDARD.encode(payload.get_body()).into());
+    }
+    Ok(Value::Object(json))
+}
+
+// `to_json` pretty printed.
+pub fn to_json_string(payload: &Payload) -> Result<String, SparkplugError> {
+    Ok(format!("{:#}", to_json(payload)?))
+}
+
+// Reads a payload from its JSON form. Fields not listed above are ignored.
+pub fn from_json(json: &Value) -> Result<Payload, SparkplugError> {
+    let object = as_object(json, "payload")?;
+    let mut payload = Payload::new();
+    if let Some(timestamp) = get_u64(object, "timestamp")? {
+        payload.set_timestamp(timestamp);
+    }
+    for metric in get_array(object, "metrics")? {
+        payload.mut_metrics().push(metric_from_json(metric)?);
+    }
+    if let Some(seq) = get_u64(object, "seq")? {
+        payload.set_seq(seq);
+    }
+    if let Some(uuid) = get_str(object, "uuid")? {
+        payload.set_uuid(uuid.into());
+    }
+    if let Some(body) = get_str(object, "body")? {
+        let body = STANDARD
+            .decode(body)
+            .map_err(|e| SparkplugError::InvalidJson(format!("`body`: {}", e)))?;
+        payload.set_body(body);
+    }
+    Ok(payload)
+}
+
+pub fn from_json_str(json: &str) -> Result<Payload, SparkplugError> {
+    let json =
+        serde_json::from_str(json).map_err(|e| SparkplugError::InvalidJson(e.to_string()))?;
+    from_json(&json)
+}
+
+fn metric_to_json(metric: &Payload_Metric) -> Result<Value, SparkplugError> {
+    let mut json = Map::new();
+    if metric.has_name() {
+        json.insert("name".into(), metric.get_name().into());
+    }
+    if metric.has_alias() {
+        json.insert("alias".into(), metric.get_alias().into());
+    }
+    if metric.has_timestamp() {
+        json.insert("timestamp".into(), metric.get_timestamp().into());
+    }
+    let data_type = metric_data_type(metric)?;
+    if metric.has_datatype() || metric.value.is_some() {
+        json.insert("dataType".into(), type_name(data_type).into());
+    }
+    if metric.has_is_historical() {
+        json.insert("isHistorical".into(), metric.get_is_historical().into());
+    }
+    if metric.has_is_transient() {
+        json.insert("isTransient".into(), metric.get_is_transient().into());
+    }
+    if let Some(metadata) = metric.metadata.as_ref() {
+        json.insert("metaData".into(), metadata_to_json(metadata));
+    }
+    if let Some(properties) = metric.properties.as_ref() {
+        let properties = PropertySet::from_proto(properties)?;
+        json.insert("properties".into(), properties_to_json(&properties));
+    }
+    if metric.get_is_null() {
+        json.insert("value".into(), Value::Null);
+    } else if metric.value.is_some() {
+        if let Some(value) = decode_metric_as(metric, data_type)?.value {
+            json.insert("value".into(), value_to_json(&value)?);
+        }
+    }
+    Ok(Value::Object(json))
+}
+
+fn metric_from_json(json: &Value) -> Result<Payload_Metric, SparkplugError> {
+    let object = as_object(json, "metric")?;
+    let mut metric = Payload_Metric::new();
+    if let Some(name) = get_str(object, "name")? {
+        metric.set_name(name.into());
+    }
+    if let Some(alias) = get_u64(object, "alias")? {
+        metric.set_alias(alias);
+    }
+    if let Some(timestamp) = get_u64(object, "timestamp")? {
+        metric.set_timestamp(timestamp);
+    }
+    let data_type = object.get("dataType").map(type_from_json).transpose()?;
+    if let Some(data_type) = data_type {
+        metric.set_datatype(data_type as u32);
+    }
+    if let Some(historical) = get_bool(object, "isHistorical")? {
+        metric.set_is_historical(historical);
+    }
+    if let Some(transient) = get_bool(object, "isTransient")? {
+        metric.set_is_transient(transient);
+    }
+    if let Some(metadata) = object.get("metaData") {
+        metric.set_metadata(metadata_from_json(metadata)?);
+    }
+    if let Some(properties) = object.get("properties") {
+        metric.set_properties(properties_from_json(properties)?.to_proto());
+    }
+    match object.get("value") {
+        None => (),
+        Some(Value::Null) => metric.set_is_null(true),
+        Some(value) => {
+            let label = metric_label(&metric);
+            let data_type = data_type.ok_or(SparkplugError::MissingDataType {
+                metric: label.clone(),
+            })?;
+            set_metric_value(&mut metric, value_from_json(&label, data_type, value)?)?;
+        }
+    }
+    Ok(metric)
+}
+
+fn value_to_json(value: &MetricValue) -> Result<Value, SparkplugError> {
+    macro_rules! array {
+        ($values:expr, $variant:ident) => {
+            Value::Array(
+                $values
+                    .iter()
+                    .map(|v| scalar_to_json(&MetricValue::$variant(v.clone())))
+                    .collect(),
+            )
+        };
+    }
+    let json = match value {
+        MetricValue::DataSet(dataset) => dataset_to_json(dataset)?,
+        MetricValue::Template(template) => template_to_json(template)?,
+        MetricValue::Bytes(v) | MetricValue::File(v) => STANDARD.encode(v).into(),
+        MetricValue::Int8Array(v) => array!(v, Int8),
+        MetricValue::Int16Array(v) => array!(v, Int16),
+        MetricValue::Int32Array(v) => array!(v, Int32),
+        MetricValue::Int64Array(v) => array!(v, Int64),
+        MetricValue::UInt8Array(v) => array!(v, UInt8),
+        MetricValue::UInt16Array(v) => array!(v, UInt16),
+        MetricValue::UInt32Array(v) => array!(v, UInt32),
+        MetricValue::UInt64Array(v) => array!(v, UInt64),
+        MetricValue::FloatArray(v) => array!(v, Float),
+        MetricValue::DoubleArray(v) => array!(v, Double),
+        MetricValue::BooleanArray(v) => array!(v, Boolean),
+        MetricValue::StringArray(v) => array!(v, String),
+        MetricValue::DateTimeArray(v) => array!(v, DateTime),
+        scalar => scalar_to_json(scalar),
+    };
+    Ok(json)
+}
+
+fn value_from_json(
+    metric: &str,
+    data_type: MetricDataType,
+    json: &Value,
+) -> Result<MetricValue, SparkplugError> {
+    let value = match data_type {
+        MetricDataType::DataSet => {
+            return dataset_value(metric, json.clone()).map(MetricValue::DataSet)
+        }
+        MetricDataType::Template => return template_from_json(json).map(MetricValue::Template),
+        t if is_scalar_type(t) => json_text(json).and_then(|text| parse_scalar(t, &text)),
+        MetricDataType::Bytes => base64_from_json(json).map(MetricValue::Bytes),
+        MetricDataType::File => base64_from_json(json).map(MetricValue::File),
+        t if t.is_array() => json.as_array().and_then(|items| array_value(t, items)),
+        _ => {
+            return Err(SparkplugError::UnsupportedType {
+                metric: metric.into(),
+                data_type,
+            })
+        }
+    };
+    value.ok_or_else(|| SparkplugError::Parse {
+        metric: metric.into(),
+        data_type,
+        value: json.to_string(),
+    })
+}
+
+// A scalar value, or null for any other.
+fn scalar_to_json(value: &MetricValue) -> Value {
+    match value {
+        MetricValue::Int8(v) => (*v).into(),
+        MetricValue::Int16(v) => (*v).into(),
+        MetricValue::Int32(v) => (*v).into(),
+        MetricValue::Int64(v) => (*v).into(),
+        MetricValue::UInt8(v) => (*v).into(),
+        MetricValue::UInt16(v) => (*v).into(),
+        MetricValue::UInt32(v) => (*v).into(),
+        MetricValue::UInt64(v) => (*v).into(),
+        // Through the shortest text of the f32, so 0.1 is not written as
+        // 0.10000000149011612.
+        MetricValue::Float(v) => float_to_json(v.to_string().parse().unwrap_or(f64::NAN)),
+        MetricValue::Double(v) => float_to_json(*v),
+        MetricValue::Boolean(v) => (*v).into(),
+        MetricValue::String(v) | MetricValue::Text(v) | MetricValue::UUID(v) => v.as_str().into(),
+        MetricValue::DateTime(v) => v.timestamp_millis().into(),
+        _ => Value::Null,
+    }
+}
+
+// JSON numbers cannot be NaN or infinite, those are written as strings.
+fn float_to_json(v: f64) -> Value {
+    match Number::from_f64(v) {
+        Some(n) => Value::Number(n),
+        None if v.is_nan() => "NaN".into(),
+        None if v > 0.0 => "Infinity".into(),
+        None => "-Infinity".into(),
+    }
+}
+
+fn base64_from_json(json: &Value) -> Option<Vec<u8>> {
+    STANDARD.decode(json.as_str()?).ok()
+}
+
+fn dataset_to_json(dataset: &Payload_DataSet) -> Result<Value, SparkplugError> {
+    let reader = DataSetReader::new(dataset)?;
+    let rows = reader
+        .rows()
+        .map(|row| {
+            Value::Array(
+                row.iter()
+                    .map(|cell| cell.as_ref().map_or(Value::Null, scalar_to_json))
+                    .collect(),
+            )
+        })
+        .collect();
+    let mut json = Map::new();
+    json.insert("numberOfColumns".into(), reader.columns().len().into());
+    json.insert("columnNames".into(), reader.columns().into());
+    json.insert(
+        "types".into(),
+        reader.types().iter().map(|t| type_name(*t)).collect(),
+    );
+    json.insert("rows".into(), Value::Array(rows));
+    Ok(Value::Object(json))
+}
+
+fn template_to_json(template: &Payload_Template) -> Result<Value, SparkplugError> {
+    let mut json = Map::new();
+    if template.has_version() {
+        json.insert("version".into(), template.get_version().into());
+    }
+    if template.has_template_ref() {
+        json.insert("templateRef".into(), template.get_template_ref().into());
+    }
+    if template.has_is_definition() {
+        json.insert("isDefinition".into(), template.get_is_definition().into());
+    }
+    let metrics = template
+        .get_metrics()
+        .iter()
+        .map(metric_to_json)
+        .collect::<Result<Vec<Value>, SparkplugError>>()?;
+    json.insert("metrics".into(), Value::Array(metrics));
+    let parameters = template
+        .get_parameters()
+        .iter()
+        .map(parameter_to_json)
+        .collect::<Result<Vec<Value>, SparkplugError>>()?;
+    json.insert("parameters".into(), Value::Array(parameters));
+    Ok(Value::Object(json))
+}
+
+fn template_from_json(json: &Value) -> Result<Payload_Template, SparkplugError> {
+    let object = as_object(json, "template")?;
+    let mut template = Payload_Template::new();
+    if let Some(version) = get_str(object, "version")? {
+        template.set_version(version.into());
+    }
+    if let Some(template_ref) = get_str(object, "templateRef")? {
+        template.set_template_ref(template_ref.into());
+    }
+    if let Some(definition) = get_bool(object, "isDefinition")? {
+        template.set_is_definition(definition);
+    }
+    for metric in get_array(object, "metrics")? {
+        template.mut_metrics().push(metric_from_json(metric)?);
+    }
+    for parameter in get_array(object, "parameters")? {
+        template
+            .mut_parameters()
+            .push(parameter_from_json(parameter)?);
+    }
+    Ok(template)
+}
+
+fn parameter_to_json(parameter: &Payload_Template_Parameter) -> Result<Value, SparkplugError> {
+    let parameter = read_parameter(parameter)?;
+    let mut json = Map::new();
+    json.insert("name".into(), parameter.name.into());
+    json.insert("type".into(), type_name(parameter.data_type).into());
+    if let Some(value) = parameter.value.as_ref() {
+        json.insert("value".into(), scalar_to_json(value));
+    }
+    Ok(Value::Object(json))
+}
+
+fn parameter_from_json(json: &Value) -> Result<Payload_Template_Parameter, SparkplugError> {
+    let object = as_object(json, "parameter")?;
+    let name = get_str(object, "name")?
+        .ok_or_else(|| SparkplugError::InvalidJson("parameter without `name`".into()))?;
+    let data_type = match object.get("type") {
+        Some(t) => type_from_json(t)?,
+        None => {
+            return Err(SparkplugError::InvalidJson(format!(
+                "parameter `{}` without `type`",
+                name
+            )))
+        }
+    };
+    match object.get("value") {
+        None | Some(Value::Null) => declare_parameter(name, data_type),
+        Some(value) => create_parameter(name, data_type, value_from_json(name, data_type, value)?),
+    }
+}
+
+fn properties_to_json(properties: &PropertySet) -> Value {
+    let mut json = Map::new();
+    for (key, value) in properties.iter() {
+        let mut property = Map::new();
+        property.insert("type".into(), type_name(value.data_type()).into());
+        let value = match value {
+            PropertyValue::Value(v) => scalar_to_json(v),
+            PropertyValue::PropertySet(set) => properties_to_json(set),
+            PropertyValue::PropertySetList(list) => {
+                Value::Array(list.iter().map(properties_to_json).collect())
+            }
+            PropertyValue::Null(_) => Value::Null,
+        };
+        property.insert("value".into(), value);
+        json.insert(key.into(), Value::Object(property));
+    }
+    Value::Object(json)
+}
+
+fn properties_from_json(json: &Value) -> Result<PropertySet, SparkplugError> {
+    let mut properties = PropertySet::new();
+    for (key, property) in as_object(json, "properties")? {
+        let property = as_object(property, key)?;
+        let data_type = match property.get("type") {
+            Some(t) => type_from_json(t)?,
+            None => {
+                return Err(SparkplugError::InvalidJson(format!(
+                    "property `{}` without `type`",
+                    key
+                )))
+            }
+        };
+        properties = match (data_type, property.get("value")) {
+            (_, None | Some(Value::Null)) => properties.insert_null(key, data_type)?,
+            (MetricDataType::PropertySet, Some(set)) => {
+                properties.insert_set(key, properties_from_json(set)?)
+            }
+            (MetricDataType::PropertySetList, Some(list)) => {
+                let list = list
+                    .as_array()
+                    .ok_or_else(|| wrong_type(key, "an array", list))?
+                    .iter()
+                    .map(properties_from_json)
+                    .collect::<Result<Vec<PropertySet>, SparkplugError>>()?;
+                properties.insert_list(key, list)
+            }
+            (_, Some(value)) => properties.insert(key, value_from_json(key, data_type, value)?)?,
+        };
+    }
+    Ok(properties)
+}
+
+fn metadata_to_json(metadata: &Payload_MetaData) -> Value {
+    let mut json = Map::new();
+    if metadata.has_is_multi_part() {
+        json.insert("isMultiPart".into(), metadata.get_is_multi_part().into());
+    }
+    if metadata.has_content_type() {
+        json.insert("contentType".into(), metadata.get_content_type().into());
+    }
+    if metadata.has_size() {
+        json.insert("size".into(), metadata.get_size().into());
+    }
+    if metadata.has_seq() {
+        json.insert("seq".into(), metadata.get_seq().into());
+    }
+    if metadata.has_file_name() {
+        json.insert("fileName".into(), metadata.get_file_name().into());
+    }
+    if metadata.has_file_type() {
+        json.insert("fileType".into(), metadata.get_file_type().into());
+    }
+    if metadata.has_md5() {
+        json.insert("md5".into(), metadata.get_md5().into());
+    }
+    if metadata.has_description() {
+        json.insert("description".into(), metadata.get_description().into());
+    }
+    Value::Object(json)
+}
+
+fn metadata_from_json(json: &Value) -> Result<Payload_MetaData, SparkplugError> {
+    let object = as_object(json, "metaData")?;
+    let mut metadata = Payload_MetaData::new();
+    if let Some(v) = get_bool(object, "isMultiPart")? {
+        metadata.set_is_multi_part(v);
+    }
+    if let Some(v) = get_str(object, "contentType")? {
+        metadata.set_content_type(v.into());
+    }
+    if let Some(v) = get_u64(object, "size")? {
+        metadata.set_size(v);
+    }
+    if let Some(v) = get_u64(object, "seq")? {
+        metadata.set_seq(v);
+    }
+    if let Some(v) = get_str(object, "fileName")? {
+        metadata.set_file_name(v.into());
+    }
+    if let Some(v) = get_str(object, "fileType")? {
+        metadata.set_file_type(v.into());
+    }
+    if let Some(v) = get_str(object, "md5")? {
+        metadata.set_md5(v.into());
+    }
+    if let Some(v) = get_str(object, "description")? {
+        metadata.set_description(v.into());
+    }
+    Ok(metadata)
+}
+
+// Tahu names datatypes as this crate does, "Int32", "DataSet", ...
+fn type_name(data_type: MetricDataType) -> String {
+    format!("{:?}", data_type)
+}
+
+// A datatype name, or its number.
+fn type_from_json(json: &Value) -> Result<MetricDataType, SparkplugError> {
+    match json {
+        Value::String(name) => MetricDataType::from_str(name).ok(),
+        Value::Number(n) => n
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .and_then(|n| MetricDataType::try_from(n).ok()),
+        _ => None,
+    }
+    .ok_or_else(|| SparkplugError::InvalidJson(format!("unknown datatype {}", json)))
+}
+
+fn wrong_type(key: &str, expected: &str, json: &Value) -> SparkplugError {
+    SparkplugError::InvalidJson(format!("`{}` must be {}, got {}", key, expected, json))
+}
+
+fn as_object<'a>(json: &'a Value, key: &str) -> Result<&'a Map<String, Value>, SparkplugError> {
+    json.as_object()
+        .ok_or_else(|| wrong_type(key, "an object", json))
+}
+
+fn get_array<'a>(object: &'a Map<String, Value>, key: &str) -> Result<&'a [Value], SparkplugError> {
+    match object.get(key) {
+        None => Ok(&[]),
+        Some(json) => json
+            .as_array()
+            .map(Vec::as_slice)
+            .ok_or_else(|| wrong_type(key, "an array", json)),
+    }
+}
+
+fn get_u64(object: &Map<String, Value>, key: &str) -> Result<Option<u64>, SparkplugError> {
+    object
+        .get(key)
+        .map(|json| {
+            json.as_u64()
+                .ok_or_else(|| wrong_type(key, "an unsigned integer", json))
+        })
+        .transpose()
+}
+
+fn get_bool(object: &Map<String, Value>, key: &str) -> Result<Option<bool>, SparkplugError> {
+    object
+        .get(key)
+        .map(|json| {
+            json.as_bool()
+                .ok_or_else(|| wrong_type(key, "a boolean", json))
+        })
+        .transpose()
+}
+
+fn get_str<'a>(
+    object: &'a Map<String, Value>,
+    key: &str,
+) -> Result<Option<&'a str>, SparkplugError> {
+    object
+        .get(key)
+        .map(|json| {
+            json.as_str()
+                .ok_or_else(|| wrong_type(key, "a string", json))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::Message;
+    use serde_json::json;
+
+    fn example() -> Value {
+        json!({
+            "timestamp": 1668114759000u64,
+            "metrics": [
+                {"name": "i8", "alias": 1, "timestamp": 1, "dataType": "Int8", "value": -1},
+                {"name": "u64", "dataType": "UInt64", "value": u64::MAX},
+                {"name": "f", "dataType": "Float", "value": "NaN"},
+                {"name": "d", "dataType": "Double", "value": "-Infinity"},
+                {"name": "s", "dataType": "String", "value": "text",
+                 "isHistorical": true, "isTransient": false},
+                {"name": "t", "dataType": "DateTime", "value": 1668114759000u64},
+                {"name": "b", "dataType": "Bytes", "value": "AQID"},
+                {"name": "null", "dataType": "Int32", "value": null},
+                {"name": "none", "dataType": "Int32"},
+                {"name": "a", "dataType": "BooleanArray", "value": [true, false, true]},
+                {"name": "sa", "dataType": "StringArray", "value": ["x", ""]},
+                {"name": "file", "dataType": "File", "value": "AA==",
+                 "metaData": {"isMultiPart": false, "fileName": "a.bin", "md5": "x"}},
+                {"name": "p", "dataType": "Int32", "value": 1, "properties": {
+                    "engUnit": {"type": "String", "value": "C"},
+                    "unset": {"type": "Int64", "value": null},
+                    "set": {"type": "PropertySet", "value": {
+                        "min": {"type": "Int16", "value": -3}
+                    }},
+                    "list": {"type": "PropertySetList", "value": [
+                        {"q": {"type": "Boolean", "value": true}}
+                    ]}
+                }},
+                {"name": "ds", "dataType": "DataSet", "value": {
+                    "numberOfColumns": 2,
+                    "columnNames": ["id", "label"],
+                    "types": ["Int8", "String"],
+                    "rows": [[-1, "a"], [2, null]]
+                }},
+                {"name": "Motor", "dataType": "Template", "value": {
+                    "version": "1.0",
+                    "isDefinition": true,
+                    "metrics": [{"name": "rpm", "dataType": "Int32", "value": 0}],
+                    "parameters": [{"name": "max", "type": "Int32", "value": -5}]
+                }}
+            ],
+            "seq": 0,
+            "uuid": "u",
+            "body": "AQI="
+        })
+    }
+
+    #[test]
+    fn round_trip_keeps_the_json_and_the_payload() {
+        let payload = from_json(&example()).unwrap();
+        let json = to_json(&payload).unwrap();
+        assert_eq!(json, example());
+        // Compared encoded, the payload holds a NaN.
+        let again = from_json(&json).unwrap();
+        assert_eq!(
+            again.write_to_bytes().unwrap(),
+            payload.write_to_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn values_go_in_their_protobuf_fields() {
+        let payload = from_json(&example()).unwrap();
+        let metrics = payload.get_metrics();
+        assert_eq!(metrics[0].get_int_value(), 255);
+        assert!(metrics[2].get_float_value().is_nan());
+        assert_eq!(metrics[6].get_bytes_value(), [1, 2, 3]);
+        assert!(metrics[7].get_is_null());
+        assert!(metrics[8].value.is_none() && !metrics[8].get_is_null());
+        assert_eq!(payload.get_body(), [1, 2]);
+    }
+
+    #[test]
+    fn metric_without_datatype_gains_the_implied_one() {
+        let mut payload = Payload::new();
+        let mut metric = Payload_Metric::new();
+        metric.set_name("m".into());
+        metric.set_int_value(7);
+        payload.mut_metrics().push(metric.clone());
+
+        let json = to_json(&payload).unwrap();
+        assert_eq!(json["metrics"][0]["dataType"], "UInt32");
+        metric.set_datatype(MetricDataType::UInt32 as u32);
+        assert_eq!(from_json(&json).unwrap().get_metrics(), [metric]);
+    }
+
+    #[test]
+    fn value_needs_a_datatype() {
+        let json = json!({"metrics": [{"name": "m", "value": 1}]});
+        assert!(matches!(
+            from_json(&json),
+            Err(SparkplugError::MissingDataType { .. })
+        ));
+        assert!(matches!(
+            from_json_str("{"),
+            Err(SparkplugError::InvalidJson(_))
+        ));
+    }
+}
@@ -1,14 +1,42 @@
-pub mod sparkplug_b;
+// `sparkplug_b`, generated from sparkplug_b.proto by build.rs.
+// `mismatched_lifetime_syntaxes` is only known to Rust 1.89 and later.
+#[allow(
+    unused_parens,
+    renamed_and_removed_lints,
+    unknown_lints,
+    mismatched_lifetime_syntaxes
+)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/mod.rs"));
+}
+pub use generated::sparkplug_b;
+pub mod alias;
+pub mod arrays;
+pub mod config;
+pub mod dataset;
+pub mod decode;
+pub mod edge_node;
+pub mod error;
+pub mod host;
+pub mod json;
+pub mod model;
+pub mod parse;
+pub mod properties;
+pub mod state;
+pub mod template;
+pub mod validate;
 
 use crate::sparkplug_b::{Payload, Payload_Metric, Payload_DataSet, Payload_Template};
-use chrono;
+use chrono::{DateTime, TimeZone, Utc};
 use protobuf::RepeatedField;
-use std::any::TypeId;
-use std::mem::size_of;
+use std::fmt;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use serde::{Deserialize};
 
+pub use crate::error::SparkplugError;
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum MessageType {
     NBIRTH = 0,
     // Birth certificate for MQTT EoN nodes.
@@ -43,6 +71,322 @@ impl MessageType {
             MessageType::STATE => "STATE",
         }
     }
+
+    pub fn is_device_message(&self) -> bool {
+        matches!(
+            self,
+            MessageType::DBIRTH | MessageType::DDEATH | MessageType::DDATA | MessageType::DCMD
+        )
+    }
+}
+
+impl fmt::Display for MessageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MessageType {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<MessageType, Self::Err> {
+        match input {
+            "NBIRTH" => Ok(MessageType::NBIRTH),
+            "NDEATH" => Ok(MessageType::NDEATH),
+            "DBIRTH" => Ok(MessageType::DBIRTH),
+            "DDEATH" => Ok(MessageType::DDEATH),
+            "NDATA" => Ok(MessageType::NDATA),
+            "DDATA" => Ok(MessageType::DDATA),
+            "NCMD" => Ok(MessageType::NCMD),
+            "DCMD" => Ok(MessageType::DCMD),
+            "STATE" => Ok(MessageType::STATE),
+            _ => Err(()),
+        }
+    }
+}
+
+// ######################################################################
+// # Topic namespace
+// ######################################################################
+// namespace/group_id/message_type/edge_node_id/[device_id]
+// namespace/STATE/host_id
+// STATE/host_id (Sparkplug 2.x)
+// ######################################################################
+pub const NAMESPACE: &str = "spBv1.0";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TopicError {
+    // The topic does not start with `spBv1.0`.
+    InvalidNamespace(String),
+    // The message type element is not one of the Sparkplug verbs.
+    InvalidMessageType(String),
+    // An id is empty or contains `+`, `#` or `/`.
+    InvalidId(String),
+    // A device message type without a device id, or a node message type with one.
+    DeviceIdMismatch(MessageType),
+    // Wrong number of topic elements.
+    Malformed(String),
+}
+
+impl fmt::Display for TopicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TopicError::InvalidNamespace(s) => write!(f, "invalid namespace `{}`", s),
+            TopicError::InvalidMessageType(s) => write!(f, "invalid message type `{}`", s),
+            TopicError::InvalidId(s) => write!(f, "invalid id `{}`", s),
+            TopicError::DeviceIdMismatch(t) if t.is_device_message() => {
+                write!(f, "{} requires a device id", t)
+            }
+            TopicError::DeviceIdMismatch(t) => write!(f, "{} must not have a device id", t),
+            TopicError::Malformed(s) => write!(f, "malformed topic `{}`", s),
+        }
+    }
+}
+
+impl std::error::Error for TopicError {}
+
+// Group, edge node, device and host ids must be non-empty and must not contain
+// any of the MQTT reserved characters.
+pub fn validate_id(id: &str) -> Result<(), TopicError> {
+    if id.is_empty() || id.contains(['+', '#', '/']) {
+        return Err(TopicError::InvalidId(id.into()));
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Topic {
+    Edge {
+        group_id: String,
+        message_type: MessageType,
+        edge_node_id: String,
+        device_id: Option<String>,
+    },
+    State {
+        host_id: String,
+        // The Sparkplug 2.x `STATE/<host_id>` form, outside the namespace.
+        legacy: bool,
+    },
+}
+
+impl Topic {
+    pub fn node(
+        group_id: &str,
+        message_type: MessageType,
+        edge_node_id: &str,
+    ) -> Result<Topic, TopicError> {
+        Topic::new(group_id, message_type, edge_node_id, None)
+    }
+
+    pub fn device(
+        group_id: &str,
+        message_type: MessageType,
+        edge_node_id: &str,
+        device_id: &str,
+    ) -> Result<Topic, TopicError> {
+        Topic::new(group_id, message_type, edge_node_id, Some(device_id))
+    }
+
+    pub fn state(host_id: &str) -> Result<Topic, TopicError> {
+        validate_id(host_id)?;
+        Ok(Topic::State {
+            host_id: host_id.into(),
+            legacy: false,
+        })
+    }
+
+    // The STATE topic of a 2.x host application.
+    pub fn legacy_state(host_id: &str) -> Result<Topic, TopicError> {
+        validate_id(host_id)?;
+        Ok(Topic::State {
+            host_id: host_id.into(),
+            legacy: true,
+        })
+    }
+
+    fn new(
+        group_id: &str,
+        message_type: MessageType,
+        edge_node_id: &str,
+        device_id: Option<&str>,
+    ) -> Result<Topic, TopicError> {
+        if message_type == MessageType::STATE
+            || message_type.is_device_message() != device_id.is_some()
+        {
+            return Err(TopicError::DeviceIdMismatch(message_type));
+        }
+        validate_id(group_id)?;
+        validate_id(edge_node_id)?;
+        if let Some(d) = device_id {
+            validate_id(d)?;
+        }
+        Ok(Topic::Edge {
+            group_id: group_id.into(),
+            message_type,
+            edge_node_id: edge_node_id.into(),
+            device_id: device_id.map(String::from),
+        })
+    }
+
+    pub fn message_type(&self) -> MessageType {
+        match self {
+            Topic::Edge { message_type, .. } => *message_type,
+            Topic::State { .. } => MessageType::STATE,
+        }
+    }
+
+    pub fn group_id(&self) -> Option<&str> {
+        match self {
+            Topic::Edge { group_id, .. } => Some(group_id),
+            Topic::State { .. } => None,
+        }
+    }
+
+    pub fn edge_node_id(&self) -> Option<&str> {
+        match self {
+            Topic::Edge { edge_node_id, .. } => Some(edge_node_id),
+            Topic::State { .. } => None,
+        }
+    }
+
+    pub fn device_id(&self) -> Option<&str> {
+        match self {
+            Topic::Edge { device_id, .. } => device_id.as_deref(),
+            Topic::State { .. } => None,
+        }
+    }
+
+    pub fn host_id(&self) -> Option<&str> {
+        match self {
+            Topic::Edge { .. } => None,
+            Topic::State { host_id, .. } => Some(host_id),
+        }
+    }
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Topic::Edge {
+                group_id,
+                message_type,
+                edge_node_id,
+                device_id,
+            } => {
+                write!(
+                    f,
+                    "{}/{}/{}/{}",
+                    NAMESPACE, group_id, message_type, edge_node_id
+                )?;
+                if let Some(d) = device_id {
+                    write!(f, "/{}", d)?;
+                }
+                Ok(())
+            }
+            Topic::State {
+                host_id,
+                legacy: false,
+            } => write!(f, "{}/STATE/{}", NAMESPACE, host_id),
+            Topic::State {
+                host_id,
+                legacy: true,
+            } => write!(f, "STATE/{}", host_id),
+        }
+    }
+}
+
+impl FromStr for Topic {
+    type Err = TopicError;
+
+    // Also accepts the Sparkplug 2.x `STATE/<host_id>` form.
+    fn from_str(input: &str) -> Result<Topic, Self::Err> {
+        let parts: Vec<&str> = input.split('/').collect();
+        match parts.as_slice() {
+            ["STATE", host_id] => Topic::legacy_state(host_id),
+            [ns, ..] if *ns != NAMESPACE => Err(TopicError::InvalidNamespace(ns.to_string())),
+            [_, "STATE", host_id] => Topic::state(host_id),
+            [_, group_id, message_type, edge_node_id, rest @ ..] if rest.len() <= 1 => {
+                let message_type = MessageType::from_str(message_type)
+                    .map_err(|_| TopicError::InvalidMessageType(message_type.to_string()))?;
+                Topic::new(group_id, message_type, edge_node_id, rest.first().copied())
+            }
+            _ => Err(TopicError::Malformed(input.into())),
+        }
+    }
+}
+
+// ######################################################################
+// # Subscription filters
+// ######################################################################
+pub struct TopicFilter;
+
+impl TopicFilter {
+    // Everything in the Sparkplug namespace, including STATE.
+    pub fn all() -> String {
+        format!("{}/#", NAMESPACE)
+    }
+
+    // Every node and device message of a group.
+    pub fn group(group_id: &str) -> Result<String, TopicError> {
+        validate_id(group_id)?;
+        Ok(format!("{}/{}/#", NAMESPACE, group_id))
+    }
+
+    // One message type, optionally restricted to a group.
+    pub fn message_type(
+        group_id: Option<&str>,
+        message_type: MessageType,
+    ) -> Result<String, TopicError> {
+        if message_type == MessageType::STATE {
+            return Ok(TopicFilter::state(None));
+        }
+        let group = match group_id {
+            Some(g) => {
+                validate_id(g)?;
+                g
+            }
+            None => "+",
+        };
+        Ok(format!("{}/{}/{}/#", NAMESPACE, group, message_type))
+    }
+
+    // An edge node and all of its devices.
+    pub fn node(group_id: &str, edge_node_id: &str) -> Result<String, TopicError> {
+        validate_id(group_id)?;
+        validate_id(edge_node_id)?;
+        Ok(format!("{}/{}/+/{}/#", NAMESPACE, group_id, edge_node_id))
+    }
+
+    // All messages of one device.
+    pub fn device(
+        group_id: &str,
+        edge_node_id: &str,
+        device_id: &str,
+    ) -> Result<String, TopicError> {
+        validate_id(group_id)?;
+        validate_id(edge_node_id)?;
+        validate_id(device_id)?;
+        Ok(format!(
+            "{}/{}/+/{}/{}",
+            NAMESPACE, group_id, edge_node_id, device_id
+        ))
+    }
+
+    // NCMD and DCMD addressed to an edge node, i.e. what the edge node itself
+    // needs to subscribe to.
+    pub fn node_commands(group_id: &str, edge_node_id: &str) -> Result<Vec<String>, TopicError> {
+        validate_id(group_id)?;
+        validate_id(edge_node_id)?;
+        Ok(vec![
+            format!("{}/{}/NCMD/{}", NAMESPACE, group_id, edge_node_id),
+            format!("{}/{}/DCMD/{}/+", NAMESPACE, group_id, edge_node_id),
+        ])
+    }
+
+    // STATE of one host application, or of every host when `None`.
+    pub fn state(host_id: Option<&str>) -> String {
+        format!("{}/STATE/{}", NAMESPACE, host_id.unwrap_or("+"))
+    }
 }
 
 // import sparkplug_b_pb2
@@ -91,9 +435,97 @@ pub enum MetricDataType {
     Bytes = 17,
     File = 18,
     Template = 19,
+    PropertySet = 20,
+    PropertySetList = 21,
+    Int8Array = 22,
+    Int16Array = 23,
+    Int32Array = 24,
+    Int64Array = 25,
+    UInt8Array = 26,
+    UInt16Array = 27,
+    UInt32Array = 28,
+    UInt64Array = 29,
+    FloatArray = 30,
+    DoubleArray = 31,
+    BooleanArray = 32,
+    StringArray = 33,
+    DateTimeArray = 34,
+}
+
+impl MetricDataType {
+    pub fn is_array(&self) -> bool {
+        (MetricDataType::Int8Array as u32..=MetricDataType::DateTimeArray as u32)
+            .contains(&(*self as u32))
+    }
+
+    // The datatype of each element of an array type.
+    pub fn element_type(&self) -> Option<MetricDataType> {
+        let element = match self {
+            MetricDataType::Int8Array => MetricDataType::Int8,
+            MetricDataType::Int16Array => MetricDataType::Int16,
+            MetricDataType::Int32Array => MetricDataType::Int32,
+            MetricDataType::Int64Array => MetricDataType::Int64,
+            MetricDataType::UInt8Array => MetricDataType::UInt8,
+            MetricDataType::UInt16Array => MetricDataType::UInt16,
+            MetricDataType::UInt32Array => MetricDataType::UInt32,
+            MetricDataType::UInt64Array => MetricDataType::UInt64,
+            MetricDataType::FloatArray => MetricDataType::Float,
+            MetricDataType::DoubleArray => MetricDataType::Double,
+            MetricDataType::BooleanArray => MetricDataType::Boolean,
+            MetricDataType::StringArray => MetricDataType::String,
+            MetricDataType::DateTimeArray => MetricDataType::DateTime,
+            _ => return None,
+        };
+        Some(element)
+    }
 }
 
 
+impl TryFrom<u32> for MetricDataType {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<MetricDataType, Self::Error> {
+        const ALL: [MetricDataType; 35] = [
+            MetricDataType::Unknown,
+            MetricDataType::Int8,
+            MetricDataType::Int16,
+            MetricDataType::Int32,
+            MetricDataType::Int64,
+            MetricDataType::UInt8,
+            MetricDataType::UInt16,
+            MetricDataType::UInt32,
+            MetricDataType::UInt64,
+            MetricDataType::Float,
+            MetricDataType::Double,
+            MetricDataType::Boolean,
+            MetricDataType::String,
+            MetricDataType::DateTime,
+            MetricDataType::Text,
+            MetricDataType::UUID,
+            MetricDataType::DataSet,
+            MetricDataType::Bytes,
+            MetricDataType::File,
+            MetricDataType::Template,
+            MetricDataType::PropertySet,
+            MetricDataType::PropertySetList,
+            MetricDataType::Int8Array,
+            MetricDataType::Int16Array,
+            MetricDataType::Int32Array,
+            MetricDataType::Int64Array,
+            MetricDataType::UInt8Array,
+            MetricDataType::UInt16Array,
+            MetricDataType::UInt32Array,
+            MetricDataType::UInt64Array,
+            MetricDataType::FloatArray,
+            MetricDataType::DoubleArray,
+            MetricDataType::BooleanArray,
+            MetricDataType::StringArray,
+            MetricDataType::DateTimeArray,
+        ];
+        ALL.get(value as usize).copied().ok_or(())
+    }
+}
+
 impl FromStr for MetricDataType {
     type Err = ();
 
@@ -119,11 +551,310 @@ impl FromStr for MetricDataType {
             "Bytes" => Ok(MetricDataType::Bytes),
             "File" => Ok(MetricDataType::File),
             "Template" => Ok(MetricDataType::Template),
+            "PropertySet" => Ok(MetricDataType::PropertySet),
+            "PropertySetList" => Ok(MetricDataType::PropertySetList),
+            "Int8Array" => Ok(MetricDataType::Int8Array),
+            "Int16Array" => Ok(MetricDataType::Int16Array),
+            "Int32Array" => Ok(MetricDataType::Int32Array),
+            "Int64Array" => Ok(MetricDataType::Int64Array),
+            "UInt8Array" => Ok(MetricDataType::UInt8Array),
+            "UInt16Array" => Ok(MetricDataType::UInt16Array),
+            "UInt32Array" => Ok(MetricDataType::UInt32Array),
+            "UInt64Array" => Ok(MetricDataType::UInt64Array),
+            "FloatArray" => Ok(MetricDataType::FloatArray),
+            "DoubleArray" => Ok(MetricDataType::DoubleArray),
+            "BooleanArray" => Ok(MetricDataType::BooleanArray),
+            "StringArray" => Ok(MetricDataType::StringArray),
+            "DateTimeArray" => Ok(MetricDataType::DateTimeArray),
             _ => Err(()),
         }
     }
 }
 
+// ######################################################################
+// # Metric values
+// ######################################################################
+// One variant per concrete `MetricDataType`, so a value always knows which
+// protobuf field it belongs in.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MetricValue {
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Float(f32),
+    Double(f64),
+    Boolean(bool),
+    String(String),
+    DateTime(DateTime<Utc>),
+    Text(String),
+    UUID(String),
+    DataSet(Payload_DataSet),
+    Bytes(Vec<u8>),
+    File(Vec<u8>),
+    Template(Payload_Template),
+    Int8Array(Vec<i8>),
+    Int16Array(Vec<i16>),
+    Int32Array(Vec<i32>),
+    Int64Array(Vec<i64>),
+    UInt8Array(Vec<u8>),
+    UInt16Array(Vec<u16>),
+    UInt32Array(Vec<u32>),
+    UInt64Array(Vec<u64>),
+    FloatArray(Vec<f32>),
+    DoubleArray(Vec<f64>),
+    BooleanArray(Vec<bool>),
+    StringArray(Vec<String>),
+    DateTimeArray(Vec<DateTime<Utc>>),
+}
+
+impl MetricValue {
+    pub fn data_type(&self) -> MetricDataType {
+        match self {
+            MetricValue::Int8(_) => MetricDataType::Int8,
+            MetricValue::Int16(_) => MetricDataType::Int16,
+            MetricValue::Int32(_) => MetricDataType::Int32,
+            MetricValue::Int64(_) => MetricDataType::Int64,
+            MetricValue::UInt8(_) => MetricDataType::UInt8,
+            MetricValue::UInt16(_) => MetricDataType::UInt16,
+            MetricValue::UInt32(_) => MetricDataType::UInt32,
+            MetricValue::UInt64(_) => MetricDataType::UInt64,
+            MetricValue::Float(_) => MetricDataType::Float,
+            MetricValue::Double(_) => MetricDataType::Double,
+            MetricValue::Boolean(_) => MetricDataType::Boolean,
+            MetricValue::String(_) => MetricDataType::String,
+            MetricValue::DateTime(_) => MetricDataType::DateTime,
+            MetricValue::Text(_) => MetricDataType::Text,
+            MetricValue::UUID(_) => MetricDataType::UUID,
+            MetricValue::DataSet(_) => MetricDataType::DataSet,
+            MetricValue::Bytes(_) => MetricDataType::Bytes,
+            MetricValue::File(_) => MetricDataType::File,
+            MetricValue::Template(_) => MetricDataType::Template,
+            MetricValue::Int8Array(_) => MetricDataType::Int8Array,
+            MetricValue::Int16Array(_) => MetricDataType::Int16Array,
+            MetricValue::Int32Array(_) => MetricDataType::Int32Array,
+            MetricValue::Int64Array(_) => MetricDataType::Int64Array,
+            MetricValue::UInt8Array(_) => MetricDataType::UInt8Array,
+            MetricValue::UInt16Array(_) => MetricDataType::UInt16Array,
+            MetricValue::UInt32Array(_) => MetricDataType::UInt32Array,
+            MetricValue::UInt64Array(_) => MetricDataType::UInt64Array,
+            MetricValue::FloatArray(_) => MetricDataType::FloatArray,
+            MetricValue::DoubleArray(_) => MetricDataType::DoubleArray,
+            MetricValue::BooleanArray(_) => MetricDataType::BooleanArray,
+            MetricValue::StringArray(_) => MetricDataType::StringArray,
+            MetricValue::DateTimeArray(_) => MetricDataType::DateTimeArray,
+        }
+    }
+
+    // Converts the value to `data_type` when that loses nothing: integers that
+    // fit the target range, Float to Double, between the string types, between
+    // Bytes, File and UInt8Array, and integer epoch milliseconds to DateTime.
+    pub fn coerce(self, data_type: MetricDataType) -> Option<MetricValue> {
+        if self.data_type() == data_type {
+            return Some(self);
+        }
+        let int = self.as_i128();
+        match (self, data_type) {
+            (MetricValue::String(s) | MetricValue::Text(s) | MetricValue::UUID(s), t) => match t {
+                MetricDataType::String => Some(MetricValue::String(s)),
+                MetricDataType::Text => Some(MetricValue::Text(s)),
+                MetricDataType::UUID => Some(MetricValue::UUID(s)),
+                _ => None,
+            },
+            (MetricValue::Bytes(b) | MetricValue::File(b) | MetricValue::UInt8Array(b), t) => {
+                match t {
+                    MetricDataType::Bytes => Some(MetricValue::Bytes(b)),
+                    MetricDataType::File => Some(MetricValue::File(b)),
+                    MetricDataType::UInt8Array => Some(MetricValue::UInt8Array(b)),
+                    _ => None,
+                }
+            }
+            (MetricValue::Float(f), MetricDataType::Double) => Some(MetricValue::Double(f.into())),
+            (_, t) => {
+                let n = int?;
+                match t {
+                    MetricDataType::Int8 => i8::try_from(n).ok().map(MetricValue::Int8),
+                    MetricDataType::Int16 => i16::try_from(n).ok().map(MetricValue::Int16),
+                    MetricDataType::Int32 => i32::try_from(n).ok().map(MetricValue::Int32),
+                    MetricDataType::Int64 => i64::try_from(n).ok().map(MetricValue::Int64),
+                    MetricDataType::UInt8 => u8::try_from(n).ok().map(MetricValue::UInt8),
+                    MetricDataType::UInt16 => u16::try_from(n).ok().map(MetricValue::UInt16),
+                    MetricDataType::UInt32 => u32::try_from(n).ok().map(MetricValue::UInt32),
+                    MetricDataType::UInt64 => u64::try_from(n).ok().map(MetricValue::UInt64),
+                    MetricDataType::DateTime => i64::try_from(n)
+                        .ok()
+                        .and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+                        .map(MetricValue::DateTime),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn as_i128(&self) -> Option<i128> {
+        match *self {
+            MetricValue::Int8(v) => Some(v.into()),
+            MetricValue::Int16(v) => Some(v.into()),
+            MetricValue::Int32(v) => Some(v.into()),
+            MetricValue::Int64(v) => Some(v.into()),
+            MetricValue::UInt8(v) => Some(v.into()),
+            MetricValue::UInt16(v) => Some(v.into()),
+            MetricValue::UInt32(v) => Some(v.into()),
+            MetricValue::UInt64(v) => Some(v.into()),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! metric_value_from {
+    ($($t:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$t> for MetricValue {
+                fn from(v: $t) -> Self {
+                    MetricValue::$variant(v)
+                }
+            }
+        )*
+    };
+}
+
+metric_value_from! {
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float,
+    f64 => Double,
+    bool => Boolean,
+    String => String,
+    DateTime<Utc> => DateTime,
+    Payload_DataSet => DataSet,
+    Vec<u8> => Bytes,
+    Payload_Template => Template,
+    Vec<i8> => Int8Array,
+    Vec<i16> => Int16Array,
+    Vec<i32> => Int32Array,
+    Vec<i64> => Int64Array,
+    Vec<u16> => UInt16Array,
+    Vec<u32> => UInt32Array,
+    Vec<u64> => UInt64Array,
+    Vec<f32> => FloatArray,
+    Vec<f64> => DoubleArray,
+    Vec<bool> => BooleanArray,
+    Vec<String> => StringArray,
+    Vec<DateTime<Utc>> => DateTimeArray,
+}
+
+impl From<&str> for MetricValue {
+    fn from(v: &str) -> Self {
+        MetricValue::String(v.into())
+    }
+}
+
+// The reverse of the `From` impls. The value goes through `coerce` first, so
+// e.g. a Float reads as f64 and a Text as String.
+macro_rules! metric_value_try_into {
+    ($($t:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl TryFrom<MetricValue> for $t {
+                type Error = ();
+                fn try_from(v: MetricValue) -> Result<Self, Self::Error> {
+                    match v.coerce(MetricDataType::$variant) {
+                        Some(MetricValue::$variant(v)) => Ok(v),
+                        _ => Err(()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+metric_value_try_into! {
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float,
+    f64 => Double,
+    bool => Boolean,
+    String => String,
+    DateTime<Utc> => DateTime,
+    Payload_DataSet => DataSet,
+    Vec<u8> => Bytes,
+    Payload_Template => Template,
+    Vec<i8> => Int8Array,
+    Vec<i16> => Int16Array,
+    Vec<i32> => Int32Array,
+    Vec<i64> => Int64Array,
+    Vec<u16> => UInt16Array,
+    Vec<u32> => UInt32Array,
+    Vec<u64> => UInt64Array,
+    Vec<f32> => FloatArray,
+    Vec<f64> => DoubleArray,
+    Vec<bool> => BooleanArray,
+    Vec<String> => StringArray,
+    Vec<DateTime<Utc>> => DateTimeArray,
+}
+
+// ######################################################################
+// # Clock
+// ######################################################################
+// Sparkplug timestamps are milliseconds since the Unix epoch in UTC. Every
+// builder takes the clock to stamp with, so payloads can be made
+// reproducible by passing a `FixedClock`.
+pub trait Clock: Send + Sync {
+    fn now_millis(&self) -> u64;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        chrono::offset::Utc::now().timestamp_millis() as u64
+    }
+}
+
+// A clock that only moves when told to.
+#[derive(Debug, Default)]
+pub struct FixedClock {
+    millis: AtomicU64,
+}
+
+impl FixedClock {
+    pub fn new(millis: u64) -> FixedClock {
+        FixedClock {
+            millis: AtomicU64::new(millis),
+        }
+    }
+
+    pub fn set(&self, millis: u64) {
+        self.millis.store(millis, Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, millis: u64) {
+        self.millis.fetch_add(millis, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_millis(&self) -> u64 {
+        self.millis.load(Ordering::SeqCst)
+    }
+}
+
 // ######################################################################
 // # Always request this before requesting the Node Birth Payload
 // ######################################################################
@@ -133,17 +864,32 @@ impl FromStr for MetricDataType {
 // return payload
 // ######################################################################
 // Not sure if this conforms with how most mqtt libs deal with will message...
-// They serialize the message before hand, so `get_bd_seq_num` will always be 0...
+// They serialize the message before hand, so the bdSeq is taken when the will is built...
 // TODO (lower priority) explore if there's a way to call this lazily if not the expectation is this cant be reliant will message
-pub fn get_node_death_payload() -> Payload {
+pub fn get_node_death_payload(
+    clock: &dyn Clock,
+    sequences: &SequenceState,
+) -> Result<Payload, SparkplugError> {
+    node_death_payload(clock, sequences.next_bd_seq())
+}
+
+pub(crate) fn node_death_payload(
+    clock: &dyn Clock,
+    bd_seq: u64,
+) -> Result<Payload, SparkplugError> {
     let mut pm = Payload::new();
     let mut container: Vec<Payload_Metric> = vec![];
-    let bseq = unsafe { get_bd_seq_num() };
-    println!("{:?}", bseq);
-    let metric = create_metric(MetricDataType::Int64, bseq, "bdSeq".into(), None, None);
+    let metric = create_metric(
+        clock,
+        MetricDataType::Int64,
+        bd_seq,
+        "bdSeq".into(),
+        None,
+        None,
+    )?;
     container.push(metric);
     pm.set_metrics(RepeatedField::from_vec(container));
-    pm
+    Ok(pm)
 }
 
 // ######################################################################
@@ -156,16 +902,39 @@ pub fn get_node_death_payload() -> Payload {
 // payload.timestamp = int(round(time.time() * 1000))
 // payload.seq = getSeqNum()
 // addMetric(payload, "bdSeq", None, MetricDataType.Int64, --bdSeq)
+// addMetric(payload, "Node Control/Rebirth", None, MetricDataType.Boolean, False)
 // return payload
-fn get_node_birth_payload() -> Payload {
+// `bd_seq` must be the value sent in the NDEATH registered for this session.
+pub fn get_node_birth_payload(
+    clock: &dyn Clock,
+    sequences: &SequenceState,
+    bd_seq: u64,
+) -> Result<Payload, SparkplugError> {
+    sequences.reset_seq();
     let mut pm = Payload::new();
-    pm.set_timestamp(chrono::offset::Utc::now().timestamp() as u64);
+    pm.set_timestamp(clock.now_millis());
+    pm.set_seq(sequences.next_seq());
     let mut container: Vec<Payload_Metric> = vec![];
-    let bseq = unsafe { get_bd_seq_num() };
-    let metric = create_metric(MetricDataType::Int64, bseq, "bdSeq".into(), None, None);
+    let metric = create_metric(
+        clock,
+        MetricDataType::Int64,
+        bd_seq,
+        "bdSeq".into(),
+        None,
+        None,
+    )?;
+    container.push(metric);
+    let metric = create_metric(
+        clock,
+        MetricDataType::Boolean,
+        false,
+        host::REBIRTH_METRIC.into(),
+        None,
+        None,
+    )?;
     container.push(metric);
     pm.set_metrics(RepeatedField::from_vec(container));
-    pm
+    Ok(pm)
 }
 
 // ######################################################################
@@ -178,10 +947,10 @@ fn get_node_birth_payload() -> Payload {
 // payload.timestamp = int(round(time.time() * 1000))
 // payload.seq = getSeqNum()
 // return payload
-pub fn get_device_birth_payload() -> Payload {
+pub fn get_device_birth_payload(clock: &dyn Clock, sequences: &SequenceState) -> Payload {
     let mut pm = Payload::new();
-    pm.set_timestamp(chrono::offset::Utc::now().timestamp() as u64);
-    let seq = unsafe { get_seq_num() };
+    pm.set_timestamp(clock.now_millis());
+    let seq = sequences.next_seq();
     pm.set_seq(seq);
     pm
 }
@@ -193,9 +962,18 @@ pub fn get_device_birth_payload() -> Payload {
 // ######################################################################
 // def getDdataPayload():
 // return getDeviceBirthPayload()
-fn get_d_data_payload() -> Payload {
-    get_device_birth_payload()
+pub fn get_d_data_payload(clock: &dyn Clock, sequences: &SequenceState) -> Payload {
+    get_device_birth_payload(clock, sequences)
+}
+
+// ######################################################################
+// # Get a DDEATH payload
+// ######################################################################
+// A DDEATH carries no metrics, only the timestamp and the next seq.
+pub fn get_device_death_payload(clock: &dyn Clock, sequences: &SequenceState) -> Payload {
+    get_device_birth_payload(clock, sequences)
 }
+
 // ######################################################################
 //
 // ######################################################################
@@ -216,27 +994,21 @@ fn get_d_data_payload() -> Payload {
 // metric.dataset_value.types.extend(types)
 // return metric.dataset_value
 
-fn init_dataset_metric(
+// Build the DataSet with `dataset::DataSetBuilder`.
+pub fn init_dataset_metric(
+    clock: &dyn Clock,
     name: String,
     alias: Option<u64>,
-    columns: Vec<String>,
-    rows_types: Vec<u32>,
+    dataset: Payload_DataSet,
 ) -> Payload_Metric {
     let mut metric = Payload_Metric::new();
-    metric.set_timestamp(chrono::offset::Utc::now().timestamp() as u64);
+    metric.set_timestamp(clock.now_millis());
 
     metric.set_name(name);
-    match alias {
-        Some(p) => metric.set_alias(p),
-        _ => {}
+    if let Some(p) = alias {
+        metric.set_alias(p);
     }
     metric.set_datatype(MetricDataType::DataSet as u32);
-
-    let mut dataset = Payload_DataSet::new();
-    dataset.set_num_of_columns(columns.len() as u64);
-    dataset.set_columns(RepeatedField::from(columns));
-    dataset.set_types(rows_types);
-
     metric.set_dataset_value(dataset);
     metric
 }
@@ -263,22 +1035,33 @@ fn init_dataset_metric(
 // metric.template_value.is_definition = True
 //
 // return metric.template_value
-fn init_template_metric(
-    mut containter: Vec<Payload_Metric>,
+// The template members and parameters are added to the returned metric, see
+// `template::TemplateDefinition` and `template::TemplateInstance`.
+pub fn init_template_metric(
+    clock: &dyn Clock,
     name: String,
     alias: Option<u64>,
-    template_ref: Payload_Template,
-) {
+    template_ref: Option<String>,
+) -> Payload_Metric {
     let mut metric = Payload_Metric::new();
     metric.set_name(name);
-    metric.set_timestamp(chrono::offset::Utc::now().timestamp() as u64);
+    metric.set_timestamp(clock.now_millis());
+
+    if let Some(p) = alias {
+        metric.set_alias(p);
+    }
+    metric.set_datatype(MetricDataType::Template as u32);
 
-    match alias {
-        Some(p) => metric.set_alias(p),
-        _ => {}
+    let mut template = Payload_Template::new();
+    match template_ref {
+        Some(template_ref) => {
+            template.set_template_ref(template_ref);
+            template.set_is_definition(false);
+        }
+        None => template.set_is_definition(true),
     }
-    metric.set_template_value(template_ref);
-    containter.push(metric);
+    metric.set_template_value(template);
+    metric
 }
 
 // ######################################################################
@@ -353,123 +1136,168 @@ fn init_template_metric(
 // # Return the metric
 // return metric
 // ######################################################################
-pub fn create_metric<T: 'static>(
+// `value` is converted to `data_type` when that is lossless (see
+// `MetricValue::coerce`), anything else is a `TypeMismatch`.
+pub fn create_metric<V: Into<MetricValue>>(
+    clock: &dyn Clock,
     data_type: MetricDataType,
-    value: T,
+    value: V,
     name: String,
     alias: Option<u64>,
     historical: Option<bool>,
-) -> Payload_Metric {
+) -> Result<Payload_Metric, SparkplugError> {
+    let value = value.into();
+    let found = value.data_type();
+    let value = value
+        .coerce(data_type)
+        .ok_or_else(|| SparkplugError::TypeMismatch {
+            metric: name.clone(),
+            data_type,
+            found: format!("{:?}", found),
+        })?;
+
     let mut metric = Payload_Metric::new();
-    metric.set_timestamp(chrono::offset::Utc::now().timestamp() as u64);
-    match historical {
-        Some(p) => metric.set_is_historical(p),
-        _ => {}
+    metric.set_timestamp(clock.now_millis());
+    if let Some(p) = historical {
+        metric.set_is_historical(p);
     }
     metric.set_name(name);
 
-    match alias {
-        Some(p) => metric.set_alias(p),
-        _ => {}
+    if let Some(p) = alias {
+        metric.set_alias(p);
     }
 
-
     set_metric_type(data_type, &mut metric);
-    set_metric_value(data_type, &mut metric, value);
-    metric
-}
-
-pub fn equals<U: 'static, V: 'static>() -> bool {
-    TypeId::of::<U>() == TypeId::of::<V>() && size_of::<U>() == size_of::<V>()
-}
-
-// TODO no idea I guess this works...
-// https://github.com/keyvank/generic-cast
-pub fn cast_ref<U: 'static, V: 'static>(u: &U) -> Option<&V> {
-    if equals::<U, V>() {
-        Some(unsafe { std::mem::transmute::<&U, &V>(u) })
-    } else {
-        None
-    }
-}
-
-pub fn cast_mut<U: 'static, V: 'static>(u: &mut U) -> Option<&mut V> {
-    if equals::<U, V>() {
-        Some(unsafe { std::mem::transmute::<&mut U, &mut V>(u) })
-    } else {
-        None
-    }
+    set_metric_value(&mut metric, value)?;
+    Ok(metric)
 }
 
 pub fn create_metric_from_str(
+    clock: &dyn Clock,
     data_type: MetricDataType,
     value: &str,
     name: String,
     alias: Option<u64>,
     historical: Option<bool>,
-) -> Payload_Metric {
+) -> Result<Payload_Metric, SparkplugError> {
     let mut metric = Payload_Metric::new();
-    metric.set_timestamp(chrono::offset::Utc::now().timestamp() as u64);
-    match historical {
-        Some(p) => metric.set_is_historical(p),
-        _ => {}
+    metric.set_timestamp(clock.now_millis());
+    if let Some(p) = historical {
+        metric.set_is_historical(p);
     }
     metric.set_name(name);
 
-    match alias {
-        Some(p) => metric.set_alias(p),
-        _ => {}
+    if let Some(p) = alias {
+        metric.set_alias(p);
     }
     set_metric_type(data_type, &mut metric);
-    set_str_metric_value(data_type, &mut metric, value);
-    metric
+    set_str_metric_value(data_type, &mut metric, value)?;
+    Ok(metric)
 }
 
-fn set_str_metric_value(data_type: MetricDataType, metric: &mut Payload_Metric, str: &str) {
-    match data_type {
-        MetricDataType::Int8 => metric.set_int_value(str.parse().unwrap()),
-        MetricDataType::Int16 => metric.set_int_value(str.parse().unwrap()),
-        MetricDataType::Int32 => metric.set_int_value(str.parse().unwrap()),
-        MetricDataType::Int64 => metric.set_long_value(str.parse().unwrap()),
-        MetricDataType::UInt8 => metric.set_int_value(str.parse().unwrap()),
-        MetricDataType::UInt16 => metric.set_int_value(str.parse().unwrap()),
-        MetricDataType::UInt32 => metric.set_int_value(str.parse().unwrap()),
-        MetricDataType::UInt64 => metric.set_long_value(str.parse().unwrap()),
-        MetricDataType::Float => metric.set_float_value(str.parse().unwrap()),
-        MetricDataType::Double => metric.set_double_value(str.parse().unwrap()),
-        MetricDataType::Boolean => metric.set_boolean_value(str.parse().unwrap()),
-        MetricDataType::String => metric.set_string_value(str.parse().unwrap()),
-        MetricDataType::DateTime => metric.set_long_value(str.parse().unwrap()),
-        MetricDataType::Text => metric.set_string_value(str.parse().unwrap()),
-        MetricDataType::UUID => metric.set_string_value(str.parse().unwrap()),
-        // MetricDataType::Bytes => metric.set_bytes_value(str.parse().unwrap()),
-        // MetricDataType::File => metric.set_bytes_value(str.parse().unwrap()),
-        _ => (),
+fn set_str_metric_value(
+    data_type: MetricDataType,
+    metric: &mut Payload_Metric,
+    str: &str,
+) -> Result<(), SparkplugError> {
+    let value = parse::parse_value(metric.get_name(), data_type, str)?;
+    set_metric_value(metric, value)
+}
+
+pub(crate) fn set_metric_value(
+    metric: &mut Payload_Metric,
+    value: MetricValue,
+) -> Result<(), SparkplugError> {
+    match set_scalar_value(metric, value) {
+        None => (),
+        Some(MetricValue::DataSet(v)) => metric.set_dataset_value(v),
+        Some(MetricValue::Bytes(v)) => metric.set_bytes_value(v),
+        Some(MetricValue::File(v)) => metric.set_bytes_value(v),
+        Some(MetricValue::Template(v)) => metric.set_template_value(v),
+        Some(array) => {
+            let bytes = arrays::encode(&array).ok_or_else(|| SparkplugError::InvalidValue {
+                metric: decode::metric_label(metric),
+                data_type: array.data_type(),
+            })?;
+            metric.set_bytes_value(bytes);
+        }
     }
+    Ok(())
 }
 
-fn set_metric_value<T: 'static>(data_type: MetricDataType, metric: &mut Payload_Metric, value: T) {
-    match data_type {
-        MetricDataType::Int8 => metric.set_int_value(*cast_ref::<T, u32>(&value).unwrap()),
-        MetricDataType::Int16 => metric.set_int_value(*cast_ref::<T, u32>(&value).unwrap()),
-        MetricDataType::Int32 => metric.set_int_value(*cast_ref::<T, u32>(&value).unwrap()),
-        MetricDataType::Int64 => metric.set_long_value(*cast_ref::<T, u64>(&value).unwrap()),
-        MetricDataType::UInt8 => metric.set_int_value(*cast_ref::<T, u32>(&value).unwrap()),
-        MetricDataType::UInt16 => metric.set_int_value(*cast_ref::<T, u32>(&value).unwrap()),
-        MetricDataType::UInt32 => metric.set_int_value(*cast_ref::<T, u32>(&value).unwrap()),
-        MetricDataType::UInt64 => metric.set_long_value(*cast_ref::<T, u64>(&value).unwrap()),
-        MetricDataType::Float => metric.set_float_value(*cast_ref::<T, f32>(&value).unwrap()),
-        MetricDataType::Double => metric.set_double_value(*cast_ref::<T, f64>(&value).unwrap()),
-        MetricDataType::Boolean => metric.set_boolean_value(*cast_ref::<T, bool>(&value).unwrap()),
-        MetricDataType::String => metric.set_string_value(cast_ref::<T, String>(&value).unwrap().clone()),
-        MetricDataType::DateTime => metric.set_long_value(*cast_ref::<T, u64>(&value).unwrap()),
-        MetricDataType::Text => metric.set_string_value(cast_ref::<T, String>(&value).unwrap().clone()),
-        MetricDataType::UUID => metric.set_string_value(cast_ref::<T, String>(&value).unwrap().clone()),
-        MetricDataType::Bytes => metric.set_bytes_value(cast_ref::<T, Vec<u8>>(&value).unwrap().clone()),
-        MetricDataType::File => metric.set_bytes_value(cast_ref::<T, Vec<u8>>(&value).unwrap().clone()),
-        MetricDataType::Template => metric.set_template_value(cast_ref::<T, Payload_Template>(&value).unwrap().clone()),
-        _ => (),
+// The int/long/float/double/boolean/string value fields shared by metrics,
+// dataset cells, template parameters and property values.
+pub(crate) trait ScalarFields {
+    fn set_int(&mut self, v: u32);
+    fn set_long(&mut self, v: u64);
+    fn set_float(&mut self, v: f32);
+    fn set_double(&mut self, v: f64);
+    fn set_boolean(&mut self, v: bool);
+    fn set_string(&mut self, v: String);
+}
+
+macro_rules! scalar_fields {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl ScalarFields for $t {
+                fn set_int(&mut self, v: u32) {
+                    self.set_int_value(v)
+                }
+                fn set_long(&mut self, v: u64) {
+                    self.set_long_value(v)
+                }
+                fn set_float(&mut self, v: f32) {
+                    self.set_float_value(v)
+                }
+                fn set_double(&mut self, v: f64) {
+                    self.set_double_value(v)
+                }
+                fn set_boolean(&mut self, v: bool) {
+                    self.set_boolean_value(v)
+                }
+                fn set_string(&mut self, v: String) {
+                    self.set_string_value(v)
+                }
+            }
+        )*
+    };
+}
+
+scalar_fields! {
+    Payload_Metric,
+    sparkplug_b::Payload_DataSet_DataSetValue,
+    sparkplug_b::Payload_Template_Parameter,
+    sparkplug_b::Payload_PropertyValue,
+}
+
+// Sparkplug 3.0 carries signed integers in the unsigned int_value and
+// long_value fields as the two's complement of their own width: Int8 -5 is
+// sent as 251, Int16 -5 as 65531, Int32 -5 as 4294967291.
+// Returns the value when it is not a scalar, i.e. an array, a DataSet, a
+// Template, Bytes or a File.
+pub(crate) fn set_scalar_value<T: ScalarFields>(
+    target: &mut T,
+    value: MetricValue,
+) -> Option<MetricValue> {
+    match value {
+        MetricValue::Int8(v) => target.set_int(v as u8 as u32),
+        MetricValue::Int16(v) => target.set_int(v as u16 as u32),
+        MetricValue::Int32(v) => target.set_int(v as u32),
+        MetricValue::Int64(v) => target.set_long(v as u64),
+        MetricValue::UInt8(v) => target.set_int(v.into()),
+        MetricValue::UInt16(v) => target.set_int(v.into()),
+        MetricValue::UInt32(v) => target.set_int(v),
+        MetricValue::UInt64(v) => target.set_long(v),
+        MetricValue::Float(v) => target.set_float(v),
+        MetricValue::Double(v) => target.set_double(v),
+        MetricValue::Boolean(v) => target.set_boolean(v),
+        MetricValue::String(v) | MetricValue::Text(v) | MetricValue::UUID(v) => {
+            target.set_string(v)
+        }
+        MetricValue::DateTime(v) => target.set_long(v.timestamp_millis() as u64),
+        other => return Some(other),
     }
+    None
 }
 
 fn set_metric_type(data_type: MetricDataType, metric: &mut Payload_Metric) {
@@ -489,9 +1317,11 @@ fn set_metric_type(data_type: MetricDataType, metric: &mut Payload_Metric) {
         MetricDataType::DateTime => metric.set_datatype(data_type as u32),
         MetricDataType::Text => metric.set_datatype(data_type as u32),
         MetricDataType::UUID => metric.set_datatype(data_type as u32),
+        MetricDataType::DataSet => metric.set_datatype(data_type as u32),
         MetricDataType::Bytes => metric.set_datatype(data_type as u32),
         MetricDataType::File => metric.set_datatype(data_type as u32),
         MetricDataType::Template => metric.set_datatype(data_type as u32),
+        t if t.is_array() => metric.set_datatype(data_type as u32),
         _ => (),
     }
 }
@@ -554,75 +1384,368 @@ fn set_metric_type(data_type: MetricDataType, metric: &mut Payload_Metric) {
 // return metric
 // ######################################################################
 //
-fn add_null_metric(
+// The value oneof stays empty, a decoded null metric has no value.
+pub fn create_null_metric(
+    clock: &dyn Clock,
     data_type: MetricDataType,
     name: String,
     alias: Option<u64>,
     historical: Option<bool>,
-) -> Payload_Metric {
+) -> Result<Payload_Metric, SparkplugError> {
+    if matches!(
+        data_type,
+        MetricDataType::Unknown | MetricDataType::PropertySet | MetricDataType::PropertySetList
+    ) {
+        return Err(SparkplugError::UnsupportedType {
+            metric: name,
+            data_type,
+        });
+    }
     let mut metric = Payload_Metric::new();
-    metric.set_timestamp(chrono::offset::Utc::now().timestamp() as u64);
+    metric.set_timestamp(clock.now_millis());
+    metric.set_is_null(true);
 
-    match historical {
-        Some(p) => metric.set_is_historical(p),
-        _ => {}
+    if let Some(p) = historical {
+        metric.set_is_historical(p);
     }
     metric.set_name(name);
 
-    match alias {
-        Some(p) => metric.set_alias(p),
-        _ => {}
+    if let Some(p) = alias {
+        metric.set_alias(p);
     }
 
     set_metric_type(data_type, &mut metric);
-    metric
+    Ok(metric)
 }
 
 // ######################################################################
-// # Helper method for getting the next sequence number
-// ######################################################################
-// def getSeqNum():
-// global seqNum
-// retVal = seqNum
-// # print("seqNum: " + str(retVal))
-// seqNum += 1
-// if seqNum == 256:
-// seqNum = 0
-// return retVal
+// # Sequence numbers
 // ######################################################################
-// TODO revisit if we can remove global
-static mut SEQ: u64 = 0;
+// Tahu keeps `seqNum` and `bdSeq` as module globals. Here each edge node owns
+// a `SequenceState`, so several edge nodes can live in one process and
+// publish from any thread. Both counters wrap from 255 back to 0.
+#[derive(Debug, Default)]
+pub struct SequenceState {
+    seq: AtomicU64,
+    bd_seq: AtomicU64,
+}
+
+impl SequenceState {
+    pub fn new() -> SequenceState {
+        SequenceState::default()
+    }
+
+    // Start from a persisted bdSeq, e.g. the one stored before a restart.
+    pub fn with_bd_seq(bd_seq: u64) -> SequenceState {
+        SequenceState {
+            seq: AtomicU64::new(0),
+            bd_seq: AtomicU64::new(bd_seq % 256),
+        }
+    }
 
-unsafe fn get_seq_num() -> u64 {
-    let ret_val = SEQ;
-    SEQ += 1;
-    if SEQ == 256 {
-        SEQ = 0;
+    // ######################################################################
+    // # Helper method for getting the next sequence number
+    // ######################################################################
+    // def getSeqNum():
+    // global seqNum
+    // retVal = seqNum
+    // # print("seqNum: " + str(retVal))
+    // seqNum += 1
+    // if seqNum == 256:
+    // seqNum = 0
+    // return retVal
+    // ######################################################################
+    pub fn next_seq(&self) -> u64 {
+        next_wrapping(&self.seq)
+    }
+
+    // ######################################################################
+    // # Helper method for getting the next birth/death sequence number
+    // ######################################################################
+    // def get_bd_seq_num():
+    // global bdSeq
+    // retVal = bdSeq
+    // # print("bdSeqNum: " + str(retVal))
+    // bdSeq += 1
+    // if bdSeq == 256:
+    // bdSeq = 0
+    // return retVal
+    // ######################################################################
+    pub fn next_bd_seq(&self) -> u64 {
+        next_wrapping(&self.bd_seq)
+    }
+
+    // The value the next call to `next_seq` will return.
+    pub fn peek_seq(&self) -> u64 {
+        self.seq.load(Ordering::SeqCst)
+    }
+
+    // The value the next call to `next_bd_seq` will return.
+    pub fn peek_bd_seq(&self) -> u64 {
+        self.bd_seq.load(Ordering::SeqCst)
+    }
+
+    pub fn reset_seq(&self) {
+        self.seq.store(0, Ordering::SeqCst);
     }
-    ret_val
 }
 
-// ######################################################################
-// # Helper method for getting the next birth/death sequence number
-// ######################################################################
-// def get_bd_seq_num():
-// global bdSeq
-// retVal = bdSeq
-// # print("bdSeqNum: " + str(retVal))
-// bdSeq += 1
-// if bdSeq == 256:
-// bdSeq = 0
-// return retVal
-// ######################################################################
+fn next_wrapping(counter: &AtomicU64) -> u64 {
+    // The closure always returns `Some`, so this never fails.
+    counter
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |v| Some((v + 1) % 256))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-// TODO revisit if we can remove global
-static mut BDSEQ: u64 = 0;
+    const NODE_TYPES: [MessageType; 4] = [
+        MessageType::NBIRTH,
+        MessageType::NDEATH,
+        MessageType::NDATA,
+        MessageType::NCMD,
+    ];
+    const DEVICE_TYPES: [MessageType; 4] = [
+        MessageType::DBIRTH,
+        MessageType::DDEATH,
+        MessageType::DDATA,
+        MessageType::DCMD,
+    ];
+    const BAD_IDS: [&str; 4] = ["", "a+b", "a#b", "a/b"];
+
+    #[test]
+    fn node_topics_round_trip() {
+        for message_type in NODE_TYPES {
+            let topic = Topic::node("group", message_type, "node").unwrap();
+            let text = format!("spBv1.0/group/{}/node", message_type);
+            assert_eq!(topic.to_string(), text);
+            assert_eq!(text.parse::<Topic>(), Ok(topic.clone()));
+            assert_eq!(topic.message_type(), message_type);
+            assert_eq!(topic.group_id(), Some("group"));
+            assert_eq!(topic.edge_node_id(), Some("node"));
+            assert_eq!(topic.device_id(), None);
+            assert_eq!(topic.host_id(), None);
+        }
+    }
+
+    #[test]
+    fn device_topics_round_trip() {
+        for message_type in DEVICE_TYPES {
+            let topic = Topic::device("group", message_type, "node", "device").unwrap();
+            let text = format!("spBv1.0/group/{}/node/device", message_type);
+            assert_eq!(topic.to_string(), text);
+            assert_eq!(text.parse::<Topic>(), Ok(topic.clone()));
+            assert_eq!(topic.message_type(), message_type);
+            assert_eq!(topic.device_id(), Some("device"));
+        }
+    }
+
+    #[test]
+    fn state_topic_round_trips() {
+        let topic = Topic::state("host").unwrap();
+        assert_eq!(topic.to_string(), "spBv1.0/STATE/host");
+        assert_eq!("spBv1.0/STATE/host".parse::<Topic>(), Ok(topic.clone()));
+        assert_eq!(topic.message_type(), MessageType::STATE);
+        assert_eq!(topic.host_id(), Some("host"));
+        assert_eq!(topic.group_id(), None);
+    }
+
+    #[test]
+    fn legacy_state_topic_round_trips() {
+        let topic = Topic::legacy_state("host").unwrap();
+        assert_eq!(topic.to_string(), "STATE/host");
+        assert_eq!("STATE/host".parse::<Topic>(), Ok(topic.clone()));
+        assert_eq!(topic.message_type(), MessageType::STATE);
+        assert_eq!(topic.host_id(), Some("host"));
+        assert_ne!(topic, Topic::state("host").unwrap());
+        for id in BAD_IDS {
+            assert_eq!(
+                Topic::legacy_state(id),
+                Err(TopicError::InvalidId(id.into()))
+            );
+        }
+        assert_eq!(
+            "STATE/a+b".parse::<Topic>(),
+            Err(TopicError::InvalidId("a+b".into()))
+        );
+    }
+
+    #[test]
+    fn device_id_must_match_message_type() {
+        for message_type in NODE_TYPES {
+            assert_eq!(
+                Topic::device("group", message_type, "node", "device"),
+                Err(TopicError::DeviceIdMismatch(message_type))
+            );
+            let text = format!("spBv1.0/group/{}/node/device", message_type);
+            assert_eq!(
+                text.parse::<Topic>(),
+                Err(TopicError::DeviceIdMismatch(message_type))
+            );
+        }
+        for message_type in DEVICE_TYPES {
+            assert_eq!(
+                Topic::node("group", message_type, "node"),
+                Err(TopicError::DeviceIdMismatch(message_type))
+            );
+            let text = format!("spBv1.0/group/{}/node", message_type);
+            assert_eq!(
+                text.parse::<Topic>(),
+                Err(TopicError::DeviceIdMismatch(message_type))
+            );
+        }
+        assert_eq!(
+            Topic::node("group", MessageType::STATE, "node"),
+            Err(TopicError::DeviceIdMismatch(MessageType::STATE))
+        );
+    }
+
+    #[test]
+    fn reserved_characters_in_ids_are_rejected() {
+        for id in BAD_IDS {
+            assert_eq!(validate_id(id), Err(TopicError::InvalidId(id.into())));
+            let invalid = Err(TopicError::InvalidId(id.into()));
+            for message_type in NODE_TYPES {
+                assert_eq!(Topic::node(id, message_type, "node"), invalid);
+                assert_eq!(Topic::node("group", message_type, id), invalid);
+            }
+            for message_type in DEVICE_TYPES {
+                assert_eq!(Topic::device(id, message_type, "node", "device"), invalid);
+                assert_eq!(Topic::device("group", message_type, id, "device"), invalid);
+                assert_eq!(Topic::device("group", message_type, "node", id), invalid);
+            }
+            assert_eq!(Topic::state(id), invalid);
+        }
+        assert_eq!(validate_id("node-1.a_b"), Ok(()));
+    }
+
+    #[test]
+    fn malformed_topics_are_rejected() {
+        assert_eq!(
+            "spAv1.0/group/NDATA/node".parse::<Topic>(),
+            Err(TopicError::InvalidNamespace("spAv1.0".into()))
+        );
+        assert_eq!(
+            "spBv1.0/group/NFOO/node".parse::<Topic>(),
+            Err(TopicError::InvalidMessageType("NFOO".into()))
+        );
+        for text in [
+            "spBv1.0/group/NDATA",
+            "spBv1.0/group/DDATA/node/device/extra",
+        ] {
+            assert_eq!(
+                text.parse::<Topic>(),
+                Err(TopicError::Malformed(text.into()))
+            );
+        }
+        assert_eq!(
+            "spBv1.0/group/NDATA/+".parse::<Topic>(),
+            Err(TopicError::InvalidId("+".into()))
+        );
+        assert_eq!(
+            "spBv1.0/STATE/#".parse::<Topic>(),
+            Err(TopicError::InvalidId("#".into()))
+        );
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(TopicFilter::all(), "spBv1.0/#");
+        assert_eq!(TopicFilter::group("group").unwrap(), "spBv1.0/group/#");
+        assert_eq!(
+            TopicFilter::message_type(None, MessageType::NBIRTH).unwrap(),
+            "spBv1.0/+/NBIRTH/#"
+        );
+        assert_eq!(
+            TopicFilter::message_type(Some("group"), MessageType::DDATA).unwrap(),
+            "spBv1.0/group/DDATA/#"
+        );
+        assert_eq!(
+            TopicFilter::message_type(Some("group"), MessageType::STATE).unwrap(),
+            "spBv1.0/STATE/+"
+        );
+        assert_eq!(
+            TopicFilter::node("group", "node").unwrap(),
+            "spBv1.0/group/+/node/#"
+        );
+        assert_eq!(
+            TopicFilter::device("group", "node", "device").unwrap(),
+            "spBv1.0/group/+/node/device"
+        );
+        assert_eq!(
+            TopicFilter::node_commands("group", "node").unwrap(),
+            vec!["spBv1.0/group/NCMD/node", "spBv1.0/group/DCMD/node/+"]
+        );
+        assert_eq!(TopicFilter::state(None), "spBv1.0/STATE/+");
+        assert_eq!(TopicFilter::state(Some("host")), "spBv1.0/STATE/host");
+    }
+
+    #[test]
+    fn filters_reject_reserved_characters() {
+        for id in BAD_IDS {
+            let invalid = TopicError::InvalidId(id.into());
+            assert_eq!(TopicFilter::group(id), Err(invalid.clone()));
+            assert_eq!(
+                TopicFilter::message_type(Some(id), MessageType::NDATA),
+                Err(invalid.clone())
+            );
+            assert_eq!(TopicFilter::node("group", id), Err(invalid.clone()));
+            assert_eq!(
+                TopicFilter::device("group", "node", id),
+                Err(invalid.clone())
+            );
+            assert_eq!(TopicFilter::node_commands(id, "node"), Err(invalid));
+        }
+    }
+
+    #[test]
+    fn seq_wraps_from_255_to_0() {
+        let sequences = SequenceState::new();
+        for expected in 0..=255 {
+            assert_eq!(sequences.next_seq(), expected);
+        }
+        assert_eq!(sequences.next_seq(), 0);
+        assert_eq!(sequences.peek_seq(), 1);
+        sequences.reset_seq();
+        assert_eq!(sequences.next_seq(), 0);
+    }
+
+    #[test]
+    fn bd_seq_wraps_from_255_to_0() {
+        let sequences = SequenceState::with_bd_seq(254);
+        assert_eq!(sequences.next_bd_seq(), 254);
+        assert_eq!(sequences.next_bd_seq(), 255);
+        assert_eq!(sequences.next_bd_seq(), 0);
+        assert_eq!(SequenceState::with_bd_seq(256 + 3).peek_bd_seq(), 3);
+    }
+
+    #[test]
+    fn node_birth_resets_seq_and_keeps_bd_seq() {
+        let clock = FixedClock::new(1000);
+        let sequences = SequenceState::new();
+        sequences.next_seq();
+        sequences.next_seq();
+        let bd_seq = sequences.next_bd_seq();
+        let birth = get_node_birth_payload(&clock, &sequences, bd_seq).unwrap();
+        assert_eq!(birth.get_seq(), 0);
+        assert_eq!(birth.get_timestamp(), 1000);
+        assert_eq!(birth.get_metrics()[0].get_name(), "bdSeq");
+        assert_eq!(birth.get_metrics()[0].get_long_value(), bd_seq);
+        assert_eq!(birth.get_metrics()[1].get_name(), host::REBIRTH_METRIC);
+        assert!(!birth.get_metrics()[1].get_boolean_value());
+        assert_eq!(sequences.peek_seq(), 1);
+    }
 
-unsafe fn get_bd_seq_num() -> u64 {
-    let ret_val = BDSEQ;
-    BDSEQ += 1;
-    if BDSEQ == 256 {
-        BDSEQ = 0;
+    #[test]
+    fn string_array_with_null_byte_is_an_invalid_value() {
+        let clock = FixedClock::new(1000);
+        let value = MetricValue::StringArray(vec!["a\0b".into()]);
+        assert!(matches!(
+            create_metric(&clock, MetricDataType::StringArray, value, "m".into(), None, None),
+            Err(SparkplugError::InvalidValue { metric, data_type: MetricDataType::StringArray })
+                if metric == "m"
+        ));
     }
-    ret_val
 }
@@ -0,0 +1,723 @@
+pub use crate::decode::MetaData;
+use crate::sparkplug_b;
+use crate::SparkplugError;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use protobuf::{Message, RepeatedField};
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// ######################################################################
+// # Serde payload model
+// ######################################################################
+// The generated protobuf types cannot derive serde. These types mirror them
+// field for field, so a payload can go through YAML, TOML, MessagePack or any
+// other serde format and come back as the same protobuf payload:
+//
+//   let model = model::Payload::from(&payload);
+//   let text = serde_json::to_string(&model)?;
+//   let model: model::Payload = serde_json::from_str(&text)?;
+//   let payload = sparkplug_b::Payload::try_from(model)?;
+//
+// Values are kept as the protobuf carries them, in the field their oneof
+// names: an Int8 of -5 is `int_value: 251` and datatypes are numbers. Use
+// `json` for the typed, Tahu style JSON. Unset fields are written as none,
+// so positional formats such as bincode work too, and may be left out when
+// reading. Byte fields are base64 strings in human readable formats and raw
+// bytes in the others. Extension values are kept as their protobuf encoding.
+// `MetaData` is the same type `decode` gives.
+//
+// Formats have their own limits: JSON has no NaN and TOML no integers above
+// i64::MAX.
+// ######################################################################
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Payload {
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+    #[serde(default)]
+    pub metrics: Vec<Metric>,
+    #[serde(default)]
+    pub seq: Option<u64>,
+    #[serde(default)]
+    pub uuid: Option<String>,
+    #[serde(default)]
+    pub body: Option<Bytes>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Metric {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub alias: Option<u64>,
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+    #[serde(default)]
+    pub datatype: Option<u32>,
+    #[serde(default)]
+    pub is_historical: Option<bool>,
+    #[serde(default)]
+    pub is_transient: Option<bool>,
+    #[serde(default)]
+    pub is_null: Option<bool>,
+    #[serde(default)]
+    pub metadata: Option<MetaData>,
+    #[serde(default)]
+    pub properties: Option<PropertySet>,
+    #[serde(default)]
+    pub value: Option<MetricValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricValue {
+    IntValue(u32),
+    LongValue(u64),
+    FloatValue(f32),
+    DoubleValue(f64),
+    BooleanValue(bool),
+    StringValue(String),
+    BytesValue(Bytes),
+    DatasetValue(DataSet),
+    TemplateValue(Template),
+    ExtensionValue(Bytes),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct DataSet {
+    #[serde(default)]
+    pub num_of_columns: Option<u64>,
+    #[serde(default)]
+    pub columns: Vec<String>,
+    #[serde(default)]
+    pub types: Vec<u32>,
+    #[serde(default)]
+    pub rows: Vec<Row>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Row {
+    #[serde(default)]
+    pub elements: Vec<DataSetValue>,
+}
+
+// `Null` is a cell with no value set.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum DataSetValue {
+    IntValue(u32),
+    LongValue(u64),
+    FloatValue(f32),
+    DoubleValue(f64),
+    BooleanValue(bool),
+    StringValue(String),
+    ExtensionValue(Bytes),
+    Null,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Template {
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub metrics: Vec<Metric>,
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
+    #[serde(default)]
+    pub template_ref: Option<String>,
+    #[serde(default)]
+    pub is_definition: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Parameter {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "type", default)]
+    pub field_type: Option<u32>,
+    #[serde(default)]
+    pub value: Option<ParameterValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ParameterValue {
+    IntValue(u32),
+    LongValue(u64),
+    FloatValue(f32),
+    DoubleValue(f64),
+    BooleanValue(bool),
+    StringValue(String),
+    ExtensionValue(Bytes),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct PropertySet {
+    #[serde(default)]
+    pub keys: Vec<String>,
+    #[serde(default)]
+    pub values: Vec<PropertyValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct PropertyValue {
+    #[serde(rename = "type", default)]
+    pub field_type: Option<u32>,
+    #[serde(default)]
+    pub is_null: Option<bool>,
+    #[serde(default)]
+    pub value: Option<PropertyValueKind>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyValueKind {
+    IntValue(u32),
+    LongValue(u64),
+    FloatValue(f32),
+    DoubleValue(f64),
+    BooleanValue(bool),
+    StringValue(String),
+    PropertysetValue(PropertySet),
+    PropertysetsValue(Vec<PropertySet>),
+    ExtensionValue(Bytes),
+}
+
+// Bytes, written as base64 in human readable formats.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&STANDARD.encode(&self.0))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("bytes, a base64 string or a sequence of bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Bytes, E> {
+        STANDARD.decode(v).map(Bytes).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Bytes, E> {
+        Ok(Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Bytes, E> {
+        Ok(Bytes(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Bytes, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        Ok(Bytes(bytes))
+    }
+}
+
+// Extension messages only hold unknown fields, their encoding cannot fail.
+fn extension_bytes<M: Message>(extension: &M) -> Bytes {
+    Bytes(extension.write_to_bytes().unwrap_or_default())
+}
+
+fn extension<M: Message>(bytes: &Bytes) -> Result<M, SparkplugError> {
+    M::parse_from_bytes(&bytes.0).map_err(SparkplugError::Decode)
+}
+
+// ######################################################################
+// # From the protobuf types
+// ######################################################################
+
+impl From<&sparkplug_b::Payload> for Payload {
+    fn from(p: &sparkplug_b::Payload) -> Self {
+        Payload {
+            timestamp: p.has_timestamp().then(|| p.get_timestamp()),
+            metrics: p.get_metrics().iter().map(Metric::from).collect(),
+            seq: p.has_seq().then(|| p.get_seq()),
+            uuid: p.has_uuid().then(|| p.get_uuid().into()),
+            body: p.has_body().then(|| Bytes(p.get_body().to_vec())),
+        }
+    }
+}
+
+impl From<&sparkplug_b::Payload_Metric> for Metric {
+    fn from(m: &sparkplug_b::Payload_Metric) -> Self {
+        use sparkplug_b::Payload_Metric_oneof_value as V;
+        Metric {
+            name: m.has_name().then(|| m.get_name().into()),
+            alias: m.has_alias().then(|| m.get_alias()),
+            timestamp: m.has_timestamp().then(|| m.get_timestamp()),
+            datatype: m.has_datatype().then(|| m.get_datatype()),
+            is_historical: m.has_is_historical().then(|| m.get_is_historical()),
+            is_transient: m.has_is_transient().then(|| m.get_is_transient()),
+            is_null: m.has_is_null().then(|| m.get_is_null()),
+            metadata: m.metadata.as_ref().map(MetaData::from),
+            properties: m.properties.as_ref().map(PropertySet::from),
+            value: m.value.as_ref().map(|v| match v {
+                V::int_value(v) => MetricValue::IntValue(*v),
+                V::long_value(v) => MetricValue::LongValue(*v),
+                V::float_value(v) => MetricValue::FloatValue(*v),
+                V::double_value(v) => MetricValue::DoubleValue(*v),
+                V::boolean_value(v) => MetricValue::BooleanValue(*v),
+                V::string_value(v) => MetricValue::StringValue(v.clone()),
+                V::bytes_value(v) => MetricValue::BytesValue(Bytes(v.clone())),
+                V::dataset_value(v) => MetricValue::DatasetValue(DataSet::from(v)),
+                V::template_value(v) => MetricValue::TemplateValue(Template::from(v)),
+                V::extension_value(v) => MetricValue::ExtensionValue(extension_bytes(v)),
+            }),
+        }
+    }
+}
+
+impl From<&sparkplug_b::Payload_DataSet> for DataSet {
+    fn from(d: &sparkplug_b::Payload_DataSet) -> Self {
+        use sparkplug_b::Payload_DataSet_DataSetValue_oneof_value as V;
+        let cell = |c: &sparkplug_b::Payload_DataSet_DataSetValue| match &c.value {
+            None => DataSetValue::Null,
+            Some(V::int_value(v)) => DataSetValue::IntValue(*v),
+            Some(V::long_value(v)) => DataSetValue::LongValue(*v),
+            Some(V::float_value(v)) => DataSetValue::FloatValue(*v),
+            Some(V::double_value(v)) => DataSetValue::DoubleValue(*v),
+            Some(V::boolean_value(v)) => DataSetValue::BooleanValue(*v),
+            Some(V::string_value(v)) => DataSetValue::StringValue(v.clone()),
+            Some(V::extension_value(v)) => DataSetValue::ExtensionValue(extension_bytes(v)),
+        };
+        DataSet {
+            num_of_columns: d.has_num_of_columns().then(|| d.get_num_of_columns()),
+            columns: d.get_columns().to_vec(),
+            types: d.get_types().to_vec(),
+            rows: d
+                .get_rows()
+                .iter()
+                .map(|r| Row {
+                    elements: r.get_elements().iter().map(cell).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&sparkplug_b::Payload_Template> for Template {
+    fn from(t: &sparkplug_b::Payload_Template) -> Self {
+        Template {
+            version: t.has_version().then(|| t.get_version().into()),
+            metrics: t.get_metrics().iter().map(Metric::from).collect(),
+            parameters: t.get_parameters().iter().map(Parameter::from).collect(),
+            template_ref: t.has_template_ref().then(|| t.get_template_ref().into()),
+            is_definition: t.has_is_definition().then(|| t.get_is_definition()),
+        }
+    }
+}
+
+impl From<&sparkplug_b::Payload_Template_Parameter> for Parameter {
+    fn from(p: &sparkplug_b::Payload_Template_Parameter) -> Self {
+        use sparkplug_b::Payload_Template_Parameter_oneof_value as V;
+        Parameter {
+            name: p.has_name().then(|| p.get_name().into()),
+            field_type: p.has_field_type().then(|| p.get_field_type()),
+            value: p.value.as_ref().map(|v| match v {
+                V::int_value(v) => ParameterValue::IntValue(*v),
+                V::long_value(v) => ParameterValue::LongValue(*v),
+                V::float_value(v) => ParameterValue::FloatValue(*v),
+                V::double_value(v) => ParameterValue::DoubleValue(*v),
+                V::boolean_value(v) => ParameterValue::BooleanValue(*v),
+                V::string_value(v) => ParameterValue::StringValue(v.clone()),
+                V::extension_value(v) => ParameterValue::ExtensionValue(extension_bytes(v)),
+            }),
+        }
+    }
+}
+
+impl From<&sparkplug_b::Payload_PropertySet> for PropertySet {
+    fn from(s: &sparkplug_b::Payload_PropertySet) -> Self {
+        PropertySet {
+            keys: s.get_keys().to_vec(),
+            values: s.get_values().iter().map(PropertyValue::from).collect(),
+        }
+    }
+}
+
+impl From<&sparkplug_b::Payload_PropertyValue> for PropertyValue {
+    fn from(p: &sparkplug_b::Payload_PropertyValue) -> Self {
+        use sparkplug_b::Payload_PropertyValue_oneof_value as V;
+        PropertyValue {
+            field_type: p.has_field_type().then(|| p.get_field_type()),
+            is_null: p.has_is_null().then(|| p.get_is_null()),
+            value: p.value.as_ref().map(|v| match v {
+                V::int_value(v) => PropertyValueKind::IntValue(*v),
+                V::long_value(v) => PropertyValueKind::LongValue(*v),
+                V::float_value(v) => PropertyValueKind::FloatValue(*v),
+                V::double_value(v) => PropertyValueKind::DoubleValue(*v),
+                V::boolean_value(v) => PropertyValueKind::BooleanValue(*v),
+                V::string_value(v) => PropertyValueKind::StringValue(v.clone()),
+                V::propertyset_value(v) => PropertyValueKind::PropertysetValue(v.into()),
+                V::propertysets_value(v) => PropertyValueKind::PropertysetsValue(
+                    v.get_propertyset().iter().map(PropertySet::from).collect(),
+                ),
+                V::extension_value(v) => PropertyValueKind::ExtensionValue(extension_bytes(v)),
+            }),
+        }
+    }
+}
+
+// ######################################################################
+// # Back to the protobuf types
+// ######################################################################
+// Only an extension value that is not a valid protobuf message fails.
+
+impl TryFrom<Payload> for sparkplug_b::Payload {
+    type Error = SparkplugError;
+
+    fn try_from(p: Payload) -> Result<Self, Self::Error> {
+        let mut payload = sparkplug_b::Payload::new();
+        if let Some(v) = p.timestamp {
+            payload.set_timestamp(v);
+        }
+        payload.set_metrics(repeated(p.metrics)?);
+        if let Some(v) = p.seq {
+            payload.set_seq(v);
+        }
+        if let Some(v) = p.uuid {
+            payload.set_uuid(v);
+        }
+        if let Some(v) = p.body {
+            payload.set_body(v.0);
+        }
+        Ok(payload)
+    }
+}
+
+impl TryFrom<Metric> for sparkplug_b::Payload_Metric {
+    type Error = SparkplugError;
+
+    fn try_from(m: Metric) -> Result<Self, Self::Error> {
+        let mut metric = sparkplug_b::Payload_Metric::new();
+        if let Some(v) = m.name {
+            metric.set_name(v);
+        }
+        if let Some(v) = m.alias {
+            metric.set_alias(v);
+        }
+        if let Some(v) = m.timestamp {
+            metric.set_timestamp(v);
+        }
+        if let Some(v) = m.datatype {
+            metric.set_datatype(v);
+        }
+        if let Some(v) = m.is_historical {
+            metric.set_is_historical(v);
+        }
+        if let Some(v) = m.is_transient {
+            metric.set_is_transient(v);
+        }
+        if let Some(v) = m.is_null {
+            metric.set_is_null(v);
+        }
+        if let Some(v) = m.metadata {
+            metric.set_metadata(v.into());
+        }
+        if let Some(v) = m.properties {
+            metric.set_properties(v.try_into()?);
+        }
+        match m.value {
+            None => (),
+            Some(MetricValue::IntValue(v)) => metric.set_int_value(v),
+            Some(MetricValue::LongValue(v)) => metric.set_long_value(v),
+            Some(MetricValue::FloatValue(v)) => metric.set_float_value(v),
+            Some(MetricValue::DoubleValue(v)) => metric.set_double_value(v),
+            Some(MetricValue::BooleanValue(v)) => metric.set_boolean_value(v),
+            Some(MetricValue::StringValue(v)) => metric.set_string_value(v),
+            Some(MetricValue::BytesValue(v)) => metric.set_bytes_value(v.0),
+            Some(MetricValue::DatasetValue(v)) => metric.set_dataset_value(v.try_into()?),
+            Some(MetricValue::TemplateValue(v)) => metric.set_template_value(v.try_into()?),
+            Some(MetricValue::ExtensionValue(v)) => metric.set_extension_value(extension(&v)?),
+        }
+        Ok(metric)
+    }
+}
+
+impl TryFrom<DataSet> for sparkplug_b::Payload_DataSet {
+    type Error = SparkplugError;
+
+    fn try_from(d: DataSet) -> Result<Self, Self::Error> {
+        let mut dataset = sparkplug_b::Payload_DataSet::new();
+        if let Some(v) = d.num_of_columns {
+            dataset.set_num_of_columns(v);
+        }
+        dataset.set_columns(RepeatedField::from_vec(d.columns));
+        dataset.set_types(d.types);
+        for r in d.rows {
+            let mut row = sparkplug_b::Payload_DataSet_Row::new();
+            for e in r.elements {
+                let mut cell = sparkplug_b::Payload_DataSet_DataSetValue::new();
+                match e {
+                    DataSetValue::Null => (),
+                    DataSetValue::IntValue(v) => cell.set_int_value(v),
+                    DataSetValue::LongValue(v) => cell.set_long_value(v),
+                    DataSetValue::FloatValue(v) => cell.set_float_value(v),
+                    DataSetValue::DoubleValue(v) => cell.set_double_value(v),
+                    DataSetValue::BooleanValue(v) => cell.set_boolean_value(v),
+                    DataSetValue::StringValue(v) => cell.set_string_value(v),
+                    DataSetValue::ExtensionValue(v) => cell.set_extension_value(extension(&v)?),
+                }
+                row.mut_elements().push(cell);
+            }
+            dataset.mut_rows().push(row);
+        }
+        Ok(dataset)
+    }
+}
+
+impl TryFrom<Template> for sparkplug_b::Payload_Template {
+    type Error = SparkplugError;
+
+    fn try_from(t: Template) -> Result<Self, Self::Error> {
+        let mut template = sparkplug_b::Payload_Template::new();
+        if let Some(v) = t.version {
+            template.set_version(v);
+        }
+        template.set_metrics(repeated(t.metrics)?);
+        template.set_parameters(repeated(t.parameters)?);
+        if let Some(v) = t.template_ref {
+            template.set_template_ref(v);
+        }
+        if let Some(v) = t.is_definition {
+            template.set_is_definition(v);
+        }
+        Ok(template)
+    }
+}
+
+impl TryFrom<Parameter> for sparkplug_b::Payload_Template_Parameter {
+    type Error = SparkplugError;
+
+    fn try_from(p: Parameter) -> Result<Self, Self::Error> {
+        let mut parameter = sparkplug_b::Payload_Template_Parameter::new();
+        if let Some(v) = p.name {
+            parameter.set_name(v);
+        }
+        if let Some(v) = p.field_type {
+            parameter.set_field_type(v);
+        }
+        match p.value {
+            None => (),
+            Some(ParameterValue::IntValue(v)) => parameter.set_int_value(v),
+            Some(ParameterValue::LongValue(v)) => parameter.set_long_value(v),
+            Some(ParameterValue::FloatValue(v)) => parameter.set_float_value(v),
+            Some(ParameterValue::DoubleValue(v)) => parameter.set_double_value(v),
+            Some(ParameterValue::BooleanValue(v)) => parameter.set_boolean_value(v),
+            Some(ParameterValue::StringValue(v)) => parameter.set_string_value(v),
+            Some(ParameterValue::ExtensionValue(v)) => {
+                parameter.set_extension_value(extension(&v)?)
+            }
+        }
+        Ok(parameter)
+    }
+}
+
+impl TryFrom<PropertySet> for sparkplug_b::Payload_PropertySet {
+    type Error = SparkplugError;
+
+    fn try_from(s: PropertySet) -> Result<Self, Self::Error> {
+        let mut set = sparkplug_b::Payload_PropertySet::new();
+        set.set_keys(RepeatedField::from_vec(s.keys));
+        set.set_values(repeated(s.values)?);
+        Ok(set)
+    }
+}
+
+impl TryFrom<PropertyValue> for sparkplug_b::Payload_PropertyValue {
+    type Error = SparkplugError;
+
+    fn try_from(p: PropertyValue) -> Result<Self, Self::Error> {
+        let mut property = sparkplug_b::Payload_PropertyValue::new();
+        if let Some(v) = p.field_type {
+            property.set_field_type(v);
+        }
+        if let Some(v) = p.is_null {
+            property.set_is_null(v);
+        }
+        match p.value {
+            None => (),
+            Some(PropertyValueKind::IntValue(v)) => property.set_int_value(v),
+            Some(PropertyValueKind::LongValue(v)) => property.set_long_value(v),
+            Some(PropertyValueKind::FloatValue(v)) => property.set_float_value(v),
+            Some(PropertyValueKind::DoubleValue(v)) => property.set_double_value(v),
+            Some(PropertyValueKind::BooleanValue(v)) => property.set_boolean_value(v),
+            Some(PropertyValueKind::StringValue(v)) => property.set_string_value(v),
+            Some(PropertyValueKind::PropertysetValue(v)) => {
+                property.set_propertyset_value(v.try_into()?)
+            }
+            Some(PropertyValueKind::PropertysetsValue(v)) => {
+                let mut list = sparkplug_b::Payload_PropertySetList::new();
+                list.set_propertyset(repeated(v)?);
+                property.set_propertysets_value(list);
+            }
+            Some(PropertyValueKind::ExtensionValue(v)) => {
+                property.set_extension_value(extension(&v)?)
+            }
+        }
+        Ok(property)
+    }
+}
+
+fn repeated<T, P>(items: Vec<T>) -> Result<RepeatedField<P>, SparkplugError>
+where
+    P: TryFrom<T, Error = SparkplugError>,
+{
+    items
+        .into_iter()
+        .map(P::try_from)
+        .collect::<Result<Vec<P>, SparkplugError>>()
+        .map(RepeatedField::from_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataset::DataSetBuilder;
+    use crate::properties::PropertySet as Properties;
+    use crate::template::{create_parameter, TemplateDefinition};
+    use crate::{create_metric, create_null_metric, FixedClock, MetricDataType};
+
+    fn payload() -> sparkplug_b::Payload {
+        let clock = FixedClock::new(1000);
+        let dataset = DataSetBuilder::new()
+            .column("id", MetricDataType::Int8)
+            .unwrap()
+            .column("label", MetricDataType::String)
+            .unwrap()
+            .row(vec![(-5i8).into(), "a".into()])
+            .unwrap()
+            .row_with_nulls(vec![Some(1i8.into()), None])
+            .unwrap()
+            .metric(&clock, "table".into(), Some(1));
+        let template = TemplateDefinition::new("Motor")
+            .with_version("1.0")
+            .member(
+                create_metric(
+                    &clock,
+                    MetricDataType::Float,
+                    0.5f32,
+                    "RPM".into(),
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )
+            .parameter(create_parameter("Unit", MetricDataType::String, "rpm").unwrap())
+            .metric(&clock);
+
+        let mut file = create_metric(
+            &clock,
+            MetricDataType::File,
+            crate::MetricValue::File(vec![0, 1, 254]),
+            "firmware".into(),
+            Some(2),
+            Some(true),
+        )
+        .unwrap();
+        file.mut_metadata().set_is_multi_part(false);
+        file.mut_metadata().set_file_name("fw.bin".into());
+        file.mut_metadata().set_size(3);
+        file.mut_metadata().set_md5("0123".into());
+        let properties = Properties::new()
+            .insert("engUnit", "V")
+            .unwrap()
+            .insert_null("engLow", MetricDataType::Double)
+            .unwrap()
+            .insert_set("quality", Properties::new().insert("code", 192i32).unwrap())
+            .insert_list("history", vec![Properties::new(), Properties::new()]);
+        file.set_properties(properties.to_proto());
+
+        let null =
+            create_null_metric(&clock, MetricDataType::Int64, "empty".into(), None, None).unwrap();
+
+        let mut payload = sparkplug_b::Payload::new();
+        payload.set_timestamp(1000);
+        payload.set_seq(7);
+        payload.set_uuid("uuid".into());
+        payload.set_body(vec![9, 8]);
+        payload.set_metrics(RepeatedField::from_vec(vec![dataset, template, file, null]));
+        payload
+    }
+
+    #[test]
+    fn payload_round_trips_through_the_model() {
+        let payload = payload();
+        let model = Payload::from(&payload);
+        let json = serde_json::to_string(&model).unwrap();
+        let read: Payload = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, model);
+        let back = sparkplug_b::Payload::try_from(read).unwrap();
+        assert_eq!(back, payload);
+        assert_eq!(
+            back.write_to_bytes().unwrap(),
+            payload.write_to_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn values_stay_in_their_protobuf_fields() {
+        let model = Payload::from(&payload());
+        let json = serde_json::to_value(&model).unwrap();
+        let cell = &json["metrics"][0]["value"]["dataset_value"]["rows"][0]["elements"][0];
+        assert_eq!(cell, &serde_json::json!({"int_value": 251}));
+        assert_eq!(
+            json["metrics"][0]["value"]["dataset_value"]["rows"][1]["elements"][1],
+            "null"
+        );
+        assert_eq!(json["metrics"][2]["value"]["bytes_value"], "AAH+");
+        assert_eq!(json["metrics"][2]["metadata"]["file_name"], "fw.bin");
+        assert_eq!(json["metrics"][3]["is_null"], true);
+        assert_eq!(json["metrics"][3]["value"], serde_json::Value::Null);
+        assert_eq!(json["body"], "CQg=");
+    }
+
+    #[test]
+    fn missing_fields_read_as_unset() {
+        let model: Payload = serde_json::from_str(r#"{"metrics": [{"name": "m"}]}"#).unwrap();
+        let payload = sparkplug_b::Payload::try_from(model).unwrap();
+        assert!(!payload.has_timestamp());
+        assert_eq!(payload.get_metrics()[0].get_name(), "m");
+        assert!(!payload.get_metrics()[0].has_datatype());
+        assert!(payload.get_metrics()[0].value.is_none());
+    }
+
+    #[test]
+    fn invalid_extension_value_is_rejected() {
+        let metric = Metric {
+            value: Some(MetricValue::ExtensionValue(Bytes(vec![0xff]))),
+            ..Metric::default()
+        };
+        assert!(matches!(
+            sparkplug_b::Payload_Metric::try_from(metric),
+            Err(SparkplugError::Decode(_))
+        ));
+    }
+}
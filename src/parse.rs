@@ -0,0 +1,624 @@
+use crate::dataset::{for_metric, DataSetBuilder};
+use crate::decode::is_scalar_type;
+use crate::sparkplug_b::Payload_DataSet;
+use crate::{MetricDataType, MetricValue, SparkplugError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use std::str::FromStr;
+
+// ######################################################################
+// # Parsing values from strings
+// ######################################################################
+// The text forms accepted for each datatype, e.g. from configuration files
+// or the command line:
+//
+//   integers, floats   Rust literal syntax, range checked: `-5`, `2.5`
+//   Boolean            `true` or `false`
+//   String, Text       taken as is
+//   UUID               `8-4-4-4-12` hex digits
+//   DateTime           RFC 3339 `2022-11-10T21:12:39Z` or epoch milliseconds
+//   Bytes, File        hex or base64, see `parse_bytes`: `0x010203`,
+//                      `010203`, `AQID`
+//   arrays             a JSON array of element literals: `[1, -2, 3]`,
+//                      `["a", "b"]`, `["2022-11-10T21:12:39Z", 0]`
+//   DataSet            a JSON object:
+//                      {"columns": ["id", "label"],
+//                       "types": ["Int32", "String"],
+//                       "rows": [[1, "a"], [2, null]]}
+//
+// Template, PropertySet and PropertySetList values have no text form.
+// ######################################################################
+
+pub fn parse_value(
+    metric: &str,
+    data_type: MetricDataType,
+    value: &str,
+) -> Result<MetricValue, SparkplugError> {
+    let parsed = match data_type {
+        t if is_scalar_type(t) => parse_scalar(t, value),
+        MetricDataType::Bytes => parse_bytes(value).map(MetricValue::Bytes),
+        MetricDataType::File => parse_bytes(value).map(MetricValue::File),
+        t if t.is_array() => serde_json::from_str::<Vec<Value>>(value)
+            .ok()
+            .and_then(|items| array_value(t, &items)),
+        MetricDataType::DataSet => {
+            let literal =
+                serde_json::from_str(value).map_err(|e| SparkplugError::InvalidDataSet {
+                    metric: metric.into(),
+                    reason: e.to_string(),
+                })?;
+            return dataset_value(metric, literal).map(MetricValue::DataSet);
+        }
+        _ => {
+            return Err(SparkplugError::UnsupportedType {
+                metric: metric.into(),
+                data_type,
+            })
+        }
+    };
+    parsed.ok_or_else(|| SparkplugError::Parse {
+        metric: metric.into(),
+        data_type,
+        value: value.into(),
+    })
+}
+
+pub(crate) fn parse_scalar(data_type: MetricDataType, value: &str) -> Option<MetricValue> {
+    let trimmed = value.trim();
+    let value = match data_type {
+        MetricDataType::Int8 => MetricValue::Int8(trimmed.parse().ok()?),
+        MetricDataType::Int16 => MetricValue::Int16(trimmed.parse().ok()?),
+        MetricDataType::Int32 => MetricValue::Int32(trimmed.parse().ok()?),
+        MetricDataType::Int64 => MetricValue::Int64(trimmed.parse().ok()?),
+        MetricDataType::UInt8 => MetricValue::UInt8(trimmed.parse().ok()?),
+        MetricDataType::UInt16 => MetricValue::UInt16(trimmed.parse().ok()?),
+        MetricDataType::UInt32 => MetricValue::UInt32(trimmed.parse().ok()?),
+        MetricDataType::UInt64 => MetricValue::UInt64(trimmed.parse().ok()?),
+        MetricDataType::Float => MetricValue::Float(trimmed.parse().ok()?),
+        MetricDataType::Double => MetricValue::Double(trimmed.parse().ok()?),
+        MetricDataType::Boolean => MetricValue::Boolean(trimmed.parse().ok()?),
+        MetricDataType::String => MetricValue::String(value.into()),
+        MetricDataType::Text => MetricValue::Text(value.into()),
+        MetricDataType::UUID if is_uuid(trimmed) => MetricValue::UUID(trimmed.into()),
+        MetricDataType::DateTime => MetricValue::DateTime(parse_datetime(trimmed)?),
+        _ => return None,
+    };
+    Some(value)
+}
+
+pub fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
+    match value.parse::<i64>() {
+        Ok(millis) => Utc.timestamp_millis_opt(millis).single(),
+        Err(_) => DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|d| d.with_timezone(&Utc)),
+    }
+}
+
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+// In order of precedence:
+//
+//   `0x`, `0X`, `hex:`   the rest is hex
+//   `base64:`            the rest is base64
+//   even number of hex   hex, so `abcd` is [0xab, 0xcd] and not base64
+//   digits only
+//   anything else        base64
+//
+// Use a prefix when a value could be read either way.
+pub fn parse_bytes(value: &str) -> Option<Vec<u8>> {
+    let value = value.trim();
+    if let Some(hex) = ["0x", "0X", "hex:"]
+        .iter()
+        .find_map(|prefix| value.strip_prefix(prefix))
+    {
+        return parse_hex(hex);
+    }
+    if let Some(encoded) = value.strip_prefix("base64:") {
+        return STANDARD.decode(encoded).ok();
+    }
+    parse_hex(value).or_else(|| STANDARD.decode(value).ok())
+}
+
+fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+macro_rules! collect_array {
+    ($values:expr, $variant:ident) => {
+        MetricValue::$variant(
+            $values
+                .into_iter()
+                .map(TryFrom::try_from)
+                .collect::<Result<_, ()>>()
+                .ok()?,
+        )
+    };
+}
+
+// An array from the JSON literals of its elements.
+pub(crate) fn array_value(data_type: MetricDataType, items: &[Value]) -> Option<MetricValue> {
+    let element = data_type.element_type()?;
+    let values = items
+        .iter()
+        .map(|item| parse_scalar(element, &json_text(item)?))
+        .collect::<Option<Vec<MetricValue>>>()?;
+    let array = match data_type {
+        MetricDataType::Int8Array => collect_array!(values, Int8Array),
+        MetricDataType::Int16Array => collect_array!(values, Int16Array),
+        MetricDataType::Int32Array => collect_array!(values, Int32Array),
+        MetricDataType::Int64Array => collect_array!(values, Int64Array),
+        MetricDataType::UInt8Array => collect_array!(values, UInt8Array),
+        MetricDataType::UInt16Array => collect_array!(values, UInt16Array),
+        MetricDataType::UInt32Array => collect_array!(values, UInt32Array),
+        MetricDataType::UInt64Array => collect_array!(values, UInt64Array),
+        MetricDataType::FloatArray => collect_array!(values, FloatArray),
+        MetricDataType::DoubleArray => collect_array!(values, DoubleArray),
+        MetricDataType::BooleanArray => collect_array!(values, BooleanArray),
+        MetricDataType::StringArray => collect_array!(values, StringArray),
+        MetricDataType::DateTimeArray => collect_array!(values, DateTimeArray),
+        _ => return None,
+    };
+    Some(array)
+}
+
+// A JSON scalar as the literal `parse_scalar` expects.
+pub(crate) fn json_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DataSetLiteral {
+    // Tahu's JSON names, `numberOfColumns` and `columnNames`, are accepted too.
+    #[serde(default, rename = "numberOfColumns")]
+    number_of_columns: Option<u64>,
+    #[serde(alias = "columnNames")]
+    columns: Vec<String>,
+    // Datatype names, or their numeric values.
+    types: Vec<Value>,
+    #[serde(default)]
+    rows: Vec<Vec<Value>>,
+}
+
+// A DataSet from its JSON object form.
+pub(crate) fn dataset_value(metric: &str, value: Value) -> Result<Payload_DataSet, SparkplugError> {
+    let invalid = |reason: String| SparkplugError::InvalidDataSet {
+        metric: metric.into(),
+        reason,
+    };
+    let literal: DataSetLiteral =
+        serde_json::from_value(value).map_err(|e| invalid(e.to_string()))?;
+    if let Some(n) = literal
+        .number_of_columns
+        .filter(|n| *n as usize != literal.columns.len())
+    {
+        return Err(invalid(format!(
+            "numberOfColumns is {} but there are {} columns",
+            n,
+            literal.columns.len()
+        )));
+    }
+    if literal.columns.len() != literal.types.len() {
+        return Err(invalid(format!(
+            "{} columns but {} types",
+            literal.columns.len(),
+            literal.types.len()
+        )));
+    }
+    let mut types = vec![];
+    let mut builder = DataSetBuilder::new();
+    for (column, t) in literal.columns.iter().zip(&literal.types) {
+        let data_type = match t {
+            Value::String(name) => MetricDataType::from_str(name).ok(),
+            Value::Number(n) => n
+                .as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .and_then(|n| MetricDataType::try_from(n).ok()),
+            _ => None,
+        }
+        .ok_or_else(|| invalid(format!("unknown type {} for column `{}`", t, column)))?;
+        builder = builder
+            .column(column, data_type)
+            .map_err(|e| for_metric(e, metric))?;
+        types.push(data_type);
+    }
+    for row in &literal.rows {
+        let mut cells = vec![];
+        for ((cell, data_type), column) in row.iter().zip(&types).zip(&literal.columns) {
+            let value = match cell {
+                Value::Null => None,
+                _ => Some(
+                    json_text(cell)
+                        .and_then(|text| parse_scalar(*data_type, &text))
+                        .ok_or_else(|| SparkplugError::Parse {
+                            metric: column.clone(),
+                            data_type: *data_type,
+                            value: cell.to_string(),
+                        })?,
+                ),
+            };
+            cells.push(value);
+        }
+        // Extra cells are reported by the builder as a width mismatch.
+        cells.extend(row.iter().skip(types.len()).map(|_| None));
+        builder = builder
+            .row_with_nulls(cells)
+            .map_err(|e| for_metric(e, metric))?;
+    }
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataset::DataSetReader;
+    use crate::decode::decode_metric;
+    use crate::{create_metric_from_str, FixedClock};
+
+    fn parse(data_type: MetricDataType, value: &str) -> MetricValue {
+        parse_value("m", data_type, value).unwrap()
+    }
+
+    fn assert_rejected(data_type: MetricDataType, value: &str) {
+        match parse_value("m", data_type, value) {
+            Err(SparkplugError::Parse {
+                metric,
+                data_type: t,
+                value: v,
+            }) => {
+                assert_eq!((metric.as_str(), t, v.as_str()), ("m", data_type, value));
+            }
+            other => panic!("{:?} `{}` parsed as {:?}", data_type, value, other),
+        }
+    }
+
+    fn datetime(millis: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(millis).unwrap()
+    }
+
+    #[test]
+    fn signed_integers_are_range_checked() {
+        assert_eq!(parse(MetricDataType::Int8, "-128"), MetricValue::Int8(-128));
+        assert_eq!(parse(MetricDataType::Int8, " 127 "), MetricValue::Int8(127));
+        assert_rejected(MetricDataType::Int8, "128");
+        assert_rejected(MetricDataType::Int8, "-129");
+        assert_eq!(
+            parse(MetricDataType::Int16, "-32768"),
+            MetricValue::Int16(-32768)
+        );
+        assert_rejected(MetricDataType::Int16, "32768");
+        assert_eq!(
+            parse(MetricDataType::Int32, "-2147483648"),
+            MetricValue::Int32(i32::MIN)
+        );
+        assert_rejected(MetricDataType::Int32, "2147483648");
+        assert_eq!(
+            parse(MetricDataType::Int64, "-9223372036854775808"),
+            MetricValue::Int64(i64::MIN)
+        );
+        assert_rejected(MetricDataType::Int64, "9223372036854775808");
+        assert_rejected(MetricDataType::Int32, "1.5");
+        assert_rejected(MetricDataType::Int32, "");
+    }
+
+    #[test]
+    fn unsigned_integers_are_range_checked() {
+        assert_eq!(parse(MetricDataType::UInt8, "255"), MetricValue::UInt8(255));
+        assert_rejected(MetricDataType::UInt8, "256");
+        assert_rejected(MetricDataType::UInt8, "-1");
+        assert_eq!(
+            parse(MetricDataType::UInt16, "65535"),
+            MetricValue::UInt16(65535)
+        );
+        assert_rejected(MetricDataType::UInt16, "65536");
+        assert_eq!(
+            parse(MetricDataType::UInt32, "4294967295"),
+            MetricValue::UInt32(u32::MAX)
+        );
+        assert_rejected(MetricDataType::UInt32, "4294967296");
+        assert_eq!(
+            parse(MetricDataType::UInt64, "18446744073709551615"),
+            MetricValue::UInt64(u64::MAX)
+        );
+        assert_rejected(MetricDataType::UInt64, "18446744073709551616");
+    }
+
+    #[test]
+    fn floats_and_booleans() {
+        assert_eq!(parse(MetricDataType::Float, "2.5"), MetricValue::Float(2.5));
+        assert_eq!(
+            parse(MetricDataType::Double, " -1e3 "),
+            MetricValue::Double(-1000.0)
+        );
+        assert_rejected(MetricDataType::Float, "two");
+        assert_rejected(MetricDataType::Double, "1,5");
+        assert_eq!(
+            parse(MetricDataType::Boolean, "true"),
+            MetricValue::Boolean(true)
+        );
+        assert_eq!(
+            parse(MetricDataType::Boolean, "false"),
+            MetricValue::Boolean(false)
+        );
+        assert_rejected(MetricDataType::Boolean, "yes");
+        assert_rejected(MetricDataType::Boolean, "1");
+    }
+
+    #[test]
+    fn strings_are_taken_as_is() {
+        assert_eq!(
+            parse(MetricDataType::String, " a b "),
+            MetricValue::String(" a b ".into())
+        );
+        assert_eq!(
+            parse(MetricDataType::Text, ""),
+            MetricValue::Text("".into())
+        );
+    }
+
+    #[test]
+    fn uuid_needs_the_hyphenated_form() {
+        let uuid = "123e4567-e89b-12d3-a456-426614174000";
+        assert_eq!(
+            parse(MetricDataType::UUID, uuid),
+            MetricValue::UUID(uuid.into())
+        );
+        assert_rejected(MetricDataType::UUID, "123e4567e89b12d3a456426614174000");
+        assert_rejected(MetricDataType::UUID, "123e4567-e89b-12d3-a456-42661417400g");
+        assert_rejected(MetricDataType::UUID, "123e4567-e89b-12d3-a456_426614174000");
+    }
+
+    #[test]
+    fn datetime_is_rfc_3339_or_epoch_millis() {
+        assert_eq!(
+            parse(MetricDataType::DateTime, "2009-10-21T05:27:55.335Z"),
+            MetricValue::DateTime(datetime(1256102875335))
+        );
+        assert_eq!(
+            parse(MetricDataType::DateTime, "2009-10-21T07:27:55.335+02:00"),
+            MetricValue::DateTime(datetime(1256102875335))
+        );
+        assert_eq!(
+            parse(MetricDataType::DateTime, "1256102875335"),
+            MetricValue::DateTime(datetime(1256102875335))
+        );
+        assert_rejected(MetricDataType::DateTime, "2009-10-21");
+        assert_rejected(MetricDataType::DateTime, "yesterday");
+        assert_eq!(parse_datetime("0"), Some(datetime(0)));
+    }
+
+    #[test]
+    fn bytes_and_files() {
+        assert_eq!(
+            parse(MetricDataType::Bytes, "0x01ff"),
+            MetricValue::Bytes(vec![1, 255])
+        );
+        assert_eq!(
+            parse(MetricDataType::File, "base64:AQI="),
+            MetricValue::File(vec![1, 2])
+        );
+        assert_eq!(
+            parse(MetricDataType::Bytes, "AQI="),
+            MetricValue::Bytes(vec![1, 2])
+        );
+        assert_eq!(
+            parse(MetricDataType::Bytes, "hex:01FF"),
+            MetricValue::Bytes(vec![1, 255])
+        );
+        assert_eq!(
+            parse(MetricDataType::Bytes, "deadBEEF"),
+            MetricValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        // Valid base64 as well, the bare hex reading wins.
+        assert_eq!(
+            parse(MetricDataType::Bytes, "abcd"),
+            MetricValue::Bytes(vec![0xab, 0xcd])
+        );
+        assert_eq!(
+            parse(MetricDataType::Bytes, "base64:abcd"),
+            MetricValue::Bytes(vec![0x69, 0xb7, 0x1d])
+        );
+        // An odd number of hex digits is not hex.
+        assert_eq!(
+            parse(MetricDataType::Bytes, "abc="),
+            MetricValue::Bytes(vec![0x69, 0xb7])
+        );
+        assert_eq!(parse(MetricDataType::Bytes, ""), MetricValue::Bytes(vec![]));
+        assert_rejected(MetricDataType::Bytes, "abc");
+        assert_rejected(MetricDataType::Bytes, "0x0");
+        assert_rejected(MetricDataType::Bytes, "hex:AQI=");
+        assert_rejected(MetricDataType::Bytes, "0xzz");
+        assert_rejected(MetricDataType::File, "base64:!");
+    }
+
+    #[test]
+    fn integer_arrays() {
+        assert_eq!(
+            parse(MetricDataType::Int8Array, "[-128, 0, 127]"),
+            MetricValue::Int8Array(vec![-128, 0, 127])
+        );
+        assert_eq!(
+            parse(MetricDataType::Int16Array, "[-2, \"3\"]"),
+            MetricValue::Int16Array(vec![-2, 3])
+        );
+        assert_eq!(
+            parse(MetricDataType::Int32Array, "[]"),
+            MetricValue::Int32Array(vec![])
+        );
+        assert_eq!(
+            parse(MetricDataType::Int64Array, "[-9223372036854775808]"),
+            MetricValue::Int64Array(vec![i64::MIN])
+        );
+        assert_eq!(
+            parse(MetricDataType::UInt8Array, "[0, 255]"),
+            MetricValue::UInt8Array(vec![0, 255])
+        );
+        assert_eq!(
+            parse(MetricDataType::UInt16Array, "[65535]"),
+            MetricValue::UInt16Array(vec![65535])
+        );
+        assert_eq!(
+            parse(MetricDataType::UInt32Array, "[4294967295]"),
+            MetricValue::UInt32Array(vec![u32::MAX])
+        );
+        assert_eq!(
+            parse(MetricDataType::UInt64Array, "[18446744073709551615]"),
+            MetricValue::UInt64Array(vec![u64::MAX])
+        );
+        assert_rejected(MetricDataType::Int8Array, "[128]");
+        assert_rejected(MetricDataType::UInt8Array, "[-1]");
+        assert_rejected(MetricDataType::Int32Array, "[1.5]");
+        assert_rejected(MetricDataType::Int32Array, "[[1]]");
+        assert_rejected(MetricDataType::Int32Array, "1, 2");
+    }
+
+    #[test]
+    fn other_arrays() {
+        assert_eq!(
+            parse(MetricDataType::FloatArray, "[1.5, -2]"),
+            MetricValue::FloatArray(vec![1.5, -2.0])
+        );
+        assert_eq!(
+            parse(MetricDataType::DoubleArray, "[1e3]"),
+            MetricValue::DoubleArray(vec![1000.0])
+        );
+        assert_eq!(
+            parse(MetricDataType::BooleanArray, "[true, \"false\"]"),
+            MetricValue::BooleanArray(vec![true, false])
+        );
+        assert_eq!(
+            parse(MetricDataType::StringArray, "[\"a\", \"\", 1]"),
+            MetricValue::StringArray(vec!["a".into(), "".into(), "1".into()])
+        );
+        assert_eq!(
+            parse(
+                MetricDataType::DateTimeArray,
+                "[\"2009-10-21T05:27:55.335Z\", 0]"
+            ),
+            MetricValue::DateTimeArray(vec![datetime(1256102875335), datetime(0)])
+        );
+        assert_rejected(MetricDataType::BooleanArray, "[1]");
+        assert_rejected(MetricDataType::StringArray, "[null]");
+        assert_rejected(MetricDataType::DateTimeArray, "[\"soon\"]");
+    }
+
+    #[test]
+    fn dataset_literal() {
+        let value = parse(
+            MetricDataType::DataSet,
+            r#"{"columns": ["id", "label"], "types": ["Int32", 12], "rows": [[1, "a"], [-2, null]]}"#,
+        );
+        let dataset = match value {
+            MetricValue::DataSet(dataset) => dataset,
+            other => panic!("expected a DataSet, got {:?}", other),
+        };
+        let reader = DataSetReader::new(&dataset).unwrap();
+        assert_eq!(
+            reader.types(),
+            [MetricDataType::Int32, MetricDataType::String]
+        );
+        assert_eq!(
+            reader.rows().collect::<Vec<_>>(),
+            [
+                vec![
+                    Some(MetricValue::Int32(1)),
+                    Some(MetricValue::String("a".into()))
+                ],
+                vec![Some(MetricValue::Int32(-2)), None],
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_dataset_literals() {
+        let invalid = |value: &str| match parse_value("table", MetricDataType::DataSet, value) {
+            Err(SparkplugError::InvalidDataSet { metric, reason }) => {
+                assert_eq!(metric, "table");
+                reason
+            }
+            other => panic!("`{}` parsed as {:?}", value, other),
+        };
+        assert_eq!(
+            invalid(r#"{"columns": ["a"], "types": []}"#),
+            "1 columns but 0 types"
+        );
+        assert_eq!(
+            invalid(r#"{"columns": ["a"], "types": ["Number"]}"#),
+            "unknown type \"Number\" for column `a`"
+        );
+        assert_eq!(
+            invalid(r#"{"columns": ["a"], "types": ["Int8"], "rows": [[1, 2]]}"#),
+            "row 0 has 2 values, expected 1"
+        );
+        invalid(r#"{"columns": ["a"], "types": ["Int8"], "other": 1}"#);
+        assert!(matches!(
+            parse_value(
+                "table",
+                MetricDataType::DataSet,
+                r#"{"columns": ["a"], "types": ["Int8"], "rows": [[300]]}"#
+            ),
+            Err(SparkplugError::Parse { metric, data_type: MetricDataType::Int8, .. })
+                if metric == "a"
+        ));
+    }
+
+    #[test]
+    fn types_without_a_text_form() {
+        for data_type in [
+            MetricDataType::Template,
+            MetricDataType::PropertySet,
+            MetricDataType::PropertySetList,
+            MetricDataType::Unknown,
+        ] {
+            assert!(matches!(
+                parse_value("m", data_type, "1"),
+                Err(SparkplugError::UnsupportedType { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn metric_from_str_holds_the_parsed_value() {
+        let clock = FixedClock::new(1000);
+        let metric = create_metric_from_str(
+            &clock,
+            MetricDataType::Int16,
+            "-5",
+            "m".into(),
+            Some(3),
+            None,
+        )
+        .unwrap();
+        assert_eq!(metric.get_datatype(), MetricDataType::Int16 as u32);
+        assert_eq!(metric.get_alias(), 3);
+        assert_eq!(
+            decode_metric(&metric).unwrap().value,
+            Some(MetricValue::Int16(-5))
+        );
+        assert!(
+            create_metric_from_str(&clock, MetricDataType::Int16, "x", "m".into(), None, None)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,388 @@
+use crate::decode::{is_scalar_type, scalar_value, Raw};
+use crate::sparkplug_b::{
+    Payload_PropertySet, Payload_PropertySetList, Payload_PropertyValue,
+    Payload_PropertyValue_oneof_value,
+};
+use crate::{set_scalar_value, MetricDataType, MetricValue, SparkplugError};
+use protobuf::RepeatedField;
+
+// ######################################################################
+// # Property sets
+// ######################################################################
+// Metrics carry extra information, such as engineering units or quality, in
+// `properties`: ordered keys, each with a typed value. A value is a scalar,
+// a nested PropertySet, a PropertySetList, or a null of a given type.
+//
+//   let properties = PropertySet::new()
+//       .insert("engUnit", "rpm")?
+//       .insert("engHigh", 3000.0)?
+//       .insert_null("engLow", MetricDataType::Double)?;
+//   metric.set_properties(properties.to_proto());
+// ######################################################################
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PropertyValue {
+    Value(MetricValue),
+    PropertySet(PropertySet),
+    PropertySetList(Vec<PropertySet>),
+    Null(MetricDataType),
+}
+
+impl PropertyValue {
+    pub fn data_type(&self) -> MetricDataType {
+        match self {
+            PropertyValue::Value(v) => v.data_type(),
+            PropertyValue::PropertySet(_) => MetricDataType::PropertySet,
+            PropertyValue::PropertySetList(_) => MetricDataType::PropertySetList,
+            PropertyValue::Null(t) => *t,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PropertySet {
+    entries: Vec<(String, PropertyValue)>,
+}
+
+impl PropertySet {
+    pub fn new() -> PropertySet {
+        PropertySet::default()
+    }
+
+    // A scalar property, replacing any property with the same key.
+    pub fn insert<V: Into<MetricValue>>(
+        self,
+        key: &str,
+        value: V,
+    ) -> Result<PropertySet, SparkplugError> {
+        let value = value.into();
+        check_scalar(key, value.data_type())?;
+        Ok(self.with(key, PropertyValue::Value(value)))
+    }
+
+    pub fn insert_null(
+        self,
+        key: &str,
+        data_type: MetricDataType,
+    ) -> Result<PropertySet, SparkplugError> {
+        if !matches!(
+            data_type,
+            MetricDataType::PropertySet | MetricDataType::PropertySetList
+        ) {
+            check_scalar(key, data_type)?;
+        }
+        Ok(self.with(key, PropertyValue::Null(data_type)))
+    }
+
+    pub fn insert_set(self, key: &str, set: PropertySet) -> PropertySet {
+        self.with(key, PropertyValue::PropertySet(set))
+    }
+
+    pub fn insert_list(self, key: &str, list: Vec<PropertySet>) -> PropertySet {
+        self.with(key, PropertyValue::PropertySetList(list))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&PropertyValue> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    // A scalar property as a Rust type, e.g. `get_as::<f64>("engHigh")`.
+    // `None` when the key is missing, null, or not convertible to `T`.
+    pub fn get_as<T: TryFrom<MetricValue>>(&self, key: &str) -> Option<T> {
+        match self.get(key) {
+            Some(PropertyValue::Value(v)) => T::try_from(v.clone()).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(k, _)| k.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PropertyValue)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn to_proto(&self) -> Payload_PropertySet {
+        let mut set = Payload_PropertySet::new();
+        for (key, value) in &self.entries {
+            set.mut_keys().push(key.clone());
+            set.mut_values().push(property_value(value));
+        }
+        set
+    }
+
+    // Reads a PropertySet, checking every value holds its declared type.
+    pub fn from_proto(set: &Payload_PropertySet) -> Result<PropertySet, SparkplugError> {
+        if set.get_keys().len() != set.get_values().len() {
+            return Err(SparkplugError::InvalidProperties(format!(
+                "{} keys but {} values",
+                set.get_keys().len(),
+                set.get_values().len()
+            )));
+        }
+        let entries = set
+            .get_keys()
+            .iter()
+            .zip(set.get_values())
+            .map(|(key, value)| Ok((key.clone(), read_property(key, value)?)))
+            .collect::<Result<Vec<(String, PropertyValue)>, SparkplugError>>()?;
+        Ok(PropertySet { entries })
+    }
+
+    fn with(mut self, key: &str, value: PropertyValue) -> PropertySet {
+        match self.entries.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.entries.push((key.into(), value)),
+        }
+        self
+    }
+}
+
+fn check_scalar(key: &str, data_type: MetricDataType) -> Result<(), SparkplugError> {
+    if is_scalar_type(data_type) {
+        Ok(())
+    } else {
+        Err(SparkplugError::UnsupportedType {
+            metric: key.into(),
+            data_type,
+        })
+    }
+}
+
+fn property_value(value: &PropertyValue) -> Payload_PropertyValue {
+    let mut property = Payload_PropertyValue::new();
+    property.set_field_type(value.data_type() as u32);
+    match value {
+        PropertyValue::Null(_) => property.set_is_null(true),
+        PropertyValue::PropertySet(set) => property.set_propertyset_value(set.to_proto()),
+        PropertyValue::PropertySetList(list) => {
+            let mut sets = Payload_PropertySetList::new();
+            sets.set_propertyset(RepeatedField::from_vec(
+                list.iter().map(PropertySet::to_proto).collect(),
+            ));
+            property.set_propertysets_value(sets);
+        }
+        PropertyValue::Value(v) => {
+            set_scalar_value(&mut property, v.clone());
+        }
+    }
+    property
+}
+
+fn read_property(
+    key: &str,
+    value: &Payload_PropertyValue,
+) -> Result<PropertyValue, SparkplugError> {
+    if !value.has_field_type() {
+        return Err(SparkplugError::MissingDataType { metric: key.into() });
+    }
+    let data_type = MetricDataType::try_from(value.get_field_type()).map_err(|_| {
+        SparkplugError::UnknownDataType {
+            metric: key.into(),
+            datatype: value.get_field_type(),
+        }
+    })?;
+    if value.get_is_null() {
+        return Ok(PropertyValue::Null(data_type));
+    }
+    let raw = match &value.value {
+        None => {
+            return Err(SparkplugError::MissingValue {
+                metric: key.into(),
+                data_type,
+            })
+        }
+        Some(raw) => raw,
+    };
+    let mismatch = |found: &str| SparkplugError::TypeMismatch {
+        metric: key.into(),
+        data_type,
+        found: found.into(),
+    };
+    let raw = match (data_type, raw) {
+        (MetricDataType::PropertySet, Payload_PropertyValue_oneof_value::propertyset_value(v)) => {
+            return Ok(PropertyValue::PropertySet(PropertySet::from_proto(v)?))
+        }
+        (
+            MetricDataType::PropertySetList,
+            Payload_PropertyValue_oneof_value::propertysets_value(v),
+        ) => {
+            return Ok(PropertyValue::PropertySetList(
+                v.get_propertyset()
+                    .iter()
+                    .map(PropertySet::from_proto)
+                    .collect::<Result<Vec<PropertySet>, SparkplugError>>()?,
+            ))
+        }
+        (_, Payload_PropertyValue_oneof_value::propertyset_value(_)) => {
+            return Err(mismatch("propertyset_value"))
+        }
+        (_, Payload_PropertyValue_oneof_value::propertysets_value(_)) => {
+            return Err(mismatch("propertysets_value"))
+        }
+        (_, Payload_PropertyValue_oneof_value::int_value(v)) => Raw::Int(*v),
+        (_, Payload_PropertyValue_oneof_value::long_value(v)) => Raw::Long(*v),
+        (_, Payload_PropertyValue_oneof_value::float_value(v)) => Raw::Float(*v),
+        (_, Payload_PropertyValue_oneof_value::double_value(v)) => Raw::Double(*v),
+        (_, Payload_PropertyValue_oneof_value::boolean_value(v)) => Raw::Boolean(*v),
+        (_, Payload_PropertyValue_oneof_value::string_value(v)) => Raw::String(v),
+        (_, Payload_PropertyValue_oneof_value::extension_value(_)) => Raw::Extension,
+    };
+    if !is_scalar_type(data_type) {
+        return Err(mismatch(raw.field()));
+    }
+    scalar_value(data_type, &raw)
+        .map(PropertyValue::Value)
+        .map_err(|_| mismatch(raw.field()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::decode_metric;
+    use crate::sparkplug_b::Payload_Metric;
+    use protobuf::Message as _;
+
+    fn quality(code: i32) -> PropertySet {
+        PropertySet::new().insert("Quality", code).unwrap()
+    }
+
+    fn properties() -> PropertySet {
+        PropertySet::new()
+            .insert("engUnit", "rpm")
+            .unwrap()
+            .insert("engHigh", 3000.0f64)
+            .unwrap()
+            .insert_null("engLow", MetricDataType::Double)
+            .unwrap()
+            .insert_set("source", quality(192).insert_set("inner", quality(0)))
+            .insert_list("history", vec![quality(0), PropertySet::new(), quality(64)])
+    }
+
+    #[test]
+    fn nested_sets_and_lists_round_trip() {
+        let properties = properties();
+        let bytes = properties.to_proto().write_to_bytes().unwrap();
+        let read = PropertySet::from_proto(&Payload_PropertySet::parse_from_bytes(&bytes).unwrap())
+            .unwrap();
+        assert_eq!(read, properties);
+        assert_eq!(
+            read.keys().collect::<Vec<_>>(),
+            ["engUnit", "engHigh", "engLow", "source", "history"]
+        );
+
+        let source = match read.get("source") {
+            Some(PropertyValue::PropertySet(set)) => set,
+            other => panic!("expected a PropertySet, got {:?}", other),
+        };
+        assert_eq!(source.get_as::<i32>("Quality"), Some(192));
+        assert_eq!(
+            source.get("inner"),
+            Some(&PropertyValue::PropertySet(quality(0)))
+        );
+        match read.get("history") {
+            Some(PropertyValue::PropertySetList(list)) => {
+                assert_eq!(list.len(), 3);
+                assert!(list[1].is_empty());
+                assert_eq!(list[2].get_as::<i32>("Quality"), Some(64));
+            }
+            other => panic!("expected a PropertySetList, got {:?}", other),
+        }
+        assert_eq!(
+            read.get("engLow").map(PropertyValue::data_type),
+            Some(MetricDataType::Double)
+        );
+    }
+
+    #[test]
+    fn insert_replaces_the_value_of_a_key() {
+        let set = properties().insert("engUnit", "rad/s").unwrap();
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.get_as::<String>("engUnit").as_deref(), Some("rad/s"));
+    }
+
+    #[test]
+    fn only_scalars_are_inserted_as_values() {
+        assert!(matches!(
+            PropertySet::new().insert("raw", vec![1u8, 2]),
+            Err(SparkplugError::UnsupportedType { metric, data_type: MetricDataType::Bytes })
+                if metric == "raw"
+        ));
+        assert!(PropertySet::new()
+            .insert_null("set", MetricDataType::PropertySet)
+            .is_ok());
+        assert!(matches!(
+            PropertySet::new().insert_null("set", MetricDataType::DataSet),
+            Err(SparkplugError::UnsupportedType { .. })
+        ));
+    }
+
+    #[test]
+    fn get_as_is_none_for_another_type() {
+        let set = properties();
+        assert_eq!(set.get_as::<f64>("engHigh"), Some(3000.0));
+        assert_eq!(set.get_as::<String>("engHigh"), None);
+        assert_eq!(set.get_as::<f32>("engHigh"), None);
+        assert_eq!(set.get_as::<f64>("engUnit"), None);
+        assert_eq!(set.get_as::<f64>("engLow"), None);
+        assert_eq!(set.get_as::<i32>("source"), None);
+        assert_eq!(set.get_as::<f64>("missing"), None);
+    }
+
+    #[test]
+    fn values_must_match_their_declared_type() {
+        let mut set = properties().to_proto();
+        set.mut_values()[1].set_field_type(MetricDataType::Int32 as u32);
+        assert!(matches!(
+            PropertySet::from_proto(&set),
+            Err(SparkplugError::TypeMismatch { metric, data_type: MetricDataType::Int32, found })
+                if metric == "engHigh" && found == "double_value"
+        ));
+
+        let mut set = properties().to_proto();
+        set.mut_values()[3].set_field_type(MetricDataType::PropertySetList as u32);
+        assert!(matches!(
+            PropertySet::from_proto(&set),
+            Err(SparkplugError::TypeMismatch { found, .. }) if found == "propertyset_value"
+        ));
+
+        let mut set = properties().to_proto();
+        set.mut_values()[0].clear_field_type();
+        assert!(matches!(
+            PropertySet::from_proto(&set),
+            Err(SparkplugError::MissingDataType { metric }) if metric == "engUnit"
+        ));
+
+        let mut set = properties().to_proto();
+        set.mut_keys().pop();
+        assert!(matches!(
+            PropertySet::from_proto(&set),
+            Err(SparkplugError::InvalidProperties(_))
+        ));
+    }
+
+    #[test]
+    fn decoded_metrics_expose_their_properties() {
+        let mut metric = Payload_Metric::new();
+        metric.set_name("speed".into());
+        metric.set_datatype(MetricDataType::Double as u32);
+        metric.set_double_value(1.5);
+        metric.set_properties(properties().to_proto());
+        let decoded = decode_metric(&metric).unwrap();
+        assert_eq!(decoded.properties, Some(properties()));
+        assert_eq!(
+            decoded.get_property::<String>("engUnit").as_deref(),
+            Some("rpm")
+        );
+        assert_eq!(decoded.get_property::<bool>("engUnit"), None);
+    }
+}
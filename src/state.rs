@@ -0,0 +1,270 @@
+use crate::{SparkplugError, Topic};
+use serde::{Deserialize, Serialize};
+
+// ######################################################################
+// # Primary host STATE messages
+// ######################################################################
+// A host application announces whether it is online on its STATE topic.
+// Sparkplug 3.0 publishes to `spBv1.0/STATE/<host_id>` a JSON payload
+//
+//   {"online":true,"timestamp":1668114759262}
+//
+// while 2.x hosts publish the string `ONLINE` or `OFFLINE` to
+// `STATE/<host_id>`.
+//
+// On connect the host registers `state_will` as its MQTT will and then
+// publishes `state_birth`, both retained at QoS 1. In the 3.0 form the two
+// must carry the same timestamp, which lets edge nodes tell a new session
+// from a stale will.
+// ######################################################################
+
+pub const STATE_QOS: u8 = 1;
+pub const STATE_RETAIN: bool = true;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StateFormat {
+    // Sparkplug 3.0 JSON payload under the namespace.
+    Json,
+    // Sparkplug 2.x ONLINE/OFFLINE string.
+    Legacy,
+}
+
+impl StateFormat {
+    // The topic the STATE of `host_id` is published on in this format.
+    pub fn topic(&self, host_id: &str) -> Result<Topic, SparkplugError> {
+        let topic = match self {
+            StateFormat::Json => Topic::state(host_id)?,
+            StateFormat::Legacy => Topic::legacy_state(host_id)?,
+        };
+        Ok(topic)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HostState {
+    pub online: bool,
+    // Epoch milliseconds of the host session, `None` for the 2.x form which
+    // carries no timestamp. The 3.0 form requires one.
+    pub timestamp: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StateJson {
+    online: bool,
+    timestamp: u64,
+}
+
+impl HostState {
+    pub fn encode(&self, format: StateFormat) -> Result<Vec<u8>, SparkplugError> {
+        let bytes = match format {
+            StateFormat::Json => {
+                let timestamp = self.timestamp.ok_or_else(|| {
+                    SparkplugError::InvalidState("a JSON STATE needs a timestamp".into())
+                })?;
+                serde_json::to_vec(&StateJson {
+                    online: self.online,
+                    timestamp,
+                })
+                .expect("STATE JSON serialization cannot fail")
+            }
+            StateFormat::Legacy => match self.online {
+                true => b"ONLINE".to_vec(),
+                false => b"OFFLINE".to_vec(),
+            },
+        };
+        Ok(bytes)
+    }
+
+    // Accepts both forms, the format is told by the payload itself.
+    pub fn parse(bytes: &[u8]) -> Result<HostState, SparkplugError> {
+        match bytes {
+            b"ONLINE" => Ok(HostState {
+                online: true,
+                timestamp: None,
+            }),
+            b"OFFLINE" => Ok(HostState {
+                online: false,
+                timestamp: None,
+            }),
+            _ => {
+                let state: StateJson = serde_json::from_slice(bytes)
+                    .map_err(|e| SparkplugError::InvalidState(e.to_string()))?;
+                Ok(HostState {
+                    online: state.online,
+                    timestamp: Some(state.timestamp),
+                })
+            }
+        }
+    }
+}
+
+// The will a host application registers when it connects.
+pub fn state_will(
+    host_id: &str,
+    timestamp: u64,
+    format: StateFormat,
+) -> Result<(Topic, Vec<u8>), SparkplugError> {
+    state_message(host_id, false, timestamp, format)
+}
+
+// Published right after connecting, with the timestamp of the will.
+pub fn state_birth(
+    host_id: &str,
+    timestamp: u64,
+    format: StateFormat,
+) -> Result<(Topic, Vec<u8>), SparkplugError> {
+    state_message(host_id, true, timestamp, format)
+}
+
+// Published explicitly before a clean disconnect.
+pub fn state_death(
+    host_id: &str,
+    timestamp: u64,
+    format: StateFormat,
+) -> Result<(Topic, Vec<u8>), SparkplugError> {
+    state_message(host_id, false, timestamp, format)
+}
+
+// The host id and state carried by a STATE message.
+pub fn decode_state(topic: &Topic, bytes: &[u8]) -> Result<(String, HostState), SparkplugError> {
+    match topic {
+        Topic::State { host_id, .. } => Ok((host_id.clone(), HostState::parse(bytes)?)),
+        Topic::Edge { message_type, .. } => Err(SparkplugError::InvalidState(format!(
+            "{} is not a STATE topic",
+            message_type
+        ))),
+    }
+}
+
+fn state_message(
+    host_id: &str,
+    online: bool,
+    timestamp: u64,
+    format: StateFormat,
+) -> Result<(Topic, Vec<u8>), SparkplugError> {
+    let state = HostState {
+        online,
+        timestamp: Some(timestamp),
+    };
+    Ok((format.topic(host_id)?, state.encode(format)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MessageType;
+
+    fn state(online: bool, timestamp: Option<u64>) -> HostState {
+        HostState { online, timestamp }
+    }
+
+    #[test]
+    fn json_state_round_trips() {
+        for online in [true, false] {
+            let bytes = state(online, Some(1668114759262))
+                .encode(StateFormat::Json)
+                .unwrap();
+            assert_eq!(
+                bytes,
+                format!(r#"{{"online":{},"timestamp":1668114759262}}"#, online).as_bytes()
+            );
+            assert_eq!(
+                HostState::parse(&bytes).unwrap(),
+                state(online, Some(1668114759262))
+            );
+        }
+    }
+
+    #[test]
+    fn json_state_needs_a_timestamp() {
+        assert!(matches!(
+            state(true, None).encode(StateFormat::Json),
+            Err(SparkplugError::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn legacy_state_round_trips() {
+        assert_eq!(
+            state(true, None).encode(StateFormat::Legacy).unwrap(),
+            b"ONLINE"
+        );
+        assert_eq!(
+            state(false, Some(5)).encode(StateFormat::Legacy).unwrap(),
+            b"OFFLINE"
+        );
+        assert_eq!(HostState::parse(b"ONLINE").unwrap(), state(true, None));
+        assert_eq!(HostState::parse(b"OFFLINE").unwrap(), state(false, None));
+    }
+
+    #[test]
+    fn topics_follow_the_format() {
+        let json = StateFormat::Json.topic("host").unwrap();
+        assert_eq!(json, Topic::state("host").unwrap());
+        assert_eq!(json.to_string(), "spBv1.0/STATE/host");
+        let legacy = StateFormat::Legacy.topic("host").unwrap();
+        assert_eq!(legacy, Topic::legacy_state("host").unwrap());
+        assert_eq!(legacy.to_string(), "STATE/host");
+        assert_eq!(legacy.to_string().parse::<Topic>(), Ok(legacy));
+        assert!(matches!(
+            StateFormat::Json.topic("a/b"),
+            Err(SparkplugError::InvalidTopic(_))
+        ));
+    }
+
+    #[test]
+    fn will_and_birth_share_the_timestamp() {
+        let (will_topic, will) = state_will("host", 42, StateFormat::Json).unwrap();
+        let (birth_topic, birth) = state_birth("host", 42, StateFormat::Json).unwrap();
+        assert_eq!(will_topic, birth_topic);
+        assert_eq!(
+            decode_state(&will_topic, &will).unwrap(),
+            ("host".to_string(), state(false, Some(42)))
+        );
+        assert_eq!(
+            decode_state(&birth_topic, &birth).unwrap(),
+            ("host".to_string(), state(true, Some(42)))
+        );
+        assert!(matches!(
+            state_birth("a/b", 42, StateFormat::Json),
+            Err(SparkplugError::InvalidTopic(_))
+        ));
+    }
+
+    #[test]
+    fn legacy_messages_decode() {
+        for (message, online) in [
+            (state_birth("host", 42, StateFormat::Legacy), true),
+            (state_death("host", 42, StateFormat::Legacy), false),
+        ] {
+            let (topic, bytes) = message.unwrap();
+            assert_eq!(topic, Topic::legacy_state("host").unwrap());
+            assert_eq!(
+                decode_state(&topic, &bytes).unwrap(),
+                ("host".to_string(), state(online, None))
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_state_is_rejected() {
+        for bytes in [
+            &b"online"[..],
+            b"",
+            br#"{"online":true}"#,
+            br#"{"online":"yes","timestamp":1}"#,
+            br#"{"online":true,"timestamp":1,"extra":0}"#,
+        ] {
+            assert!(matches!(
+                HostState::parse(bytes),
+                Err(SparkplugError::InvalidState(_))
+            ));
+        }
+        let topic = Topic::node("group", MessageType::NDATA, "node").unwrap();
+        assert!(matches!(
+            decode_state(&topic, b"ONLINE"),
+            Err(SparkplugError::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,759 @@
+use crate::decode::{decode_metric_as, is_scalar_type, scalar_value, Raw};
+use crate::sparkplug_b::{
+    Payload_Metric, Payload_Template, Payload_Template_Parameter,
+    Payload_Template_Parameter_oneof_value,
+};
+use crate::{
+    init_template_metric, set_scalar_value, Clock, MetricDataType, MetricValue, SparkplugError,
+};
+use protobuf::RepeatedField;
+use std::collections::{BTreeMap, HashSet};
+
+// ######################################################################
+// # Templates
+// ######################################################################
+// A template definition declares a reusable structure: member metrics with
+// their datatypes, and typed parameters. It is published once, in NBIRTH, as
+// a Template metric named after the definition with `is_definition` set.
+// Instances are Template metrics whose `template_ref` is that name; they can
+// appear in any birth or data message and carry member values and parameter
+// overrides.
+//
+// `TemplateRegistry` keeps the definitions an edge node publishes and checks
+// every instance against its definition before it is sent.
+//
+// Parameters hold a single scalar value. The value a parameter has in a
+// definition is its default, an instance overrides it by carrying a
+// parameter with the same name; `TemplateRegistry::parameters` resolves both.
+// ######################################################################
+
+// A template parameter read as a typed value.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Parameter {
+    pub name: String,
+    pub data_type: MetricDataType,
+    // `None` when the parameter has no value.
+    pub value: Option<MetricValue>,
+}
+
+impl Parameter {
+    // The value as a Rust type, e.g. `parameter.get::<f64>()`.
+    pub fn get<T: TryFrom<MetricValue>>(&self) -> Option<T> {
+        self.value.clone().and_then(|v| T::try_from(v).ok())
+    }
+}
+
+// A parameter of `data_type`, which must be one of the scalar datatypes.
+pub fn create_parameter<V: Into<MetricValue>>(
+    name: &str,
+    data_type: MetricDataType,
+    value: V,
+) -> Result<Payload_Template_Parameter, SparkplugError> {
+    let mut parameter = declare_parameter(name, data_type)?;
+    let value = value.into();
+    let found = value.data_type();
+    let value = value
+        .coerce(data_type)
+        .ok_or_else(|| SparkplugError::TypeMismatch {
+            metric: name.into(),
+            data_type,
+            found: format!("{:?}", found),
+        })?;
+    set_scalar_value(&mut parameter, value);
+    Ok(parameter)
+}
+
+// A parameter without a value, e.g. one a definition leaves without default.
+pub fn declare_parameter(
+    name: &str,
+    data_type: MetricDataType,
+) -> Result<Payload_Template_Parameter, SparkplugError> {
+    if !is_scalar_type(data_type) {
+        return Err(SparkplugError::UnsupportedType {
+            metric: name.into(),
+            data_type,
+        });
+    }
+    let mut parameter = Payload_Template_Parameter::new();
+    parameter.set_name(name.into());
+    parameter.set_field_type(data_type as u32);
+    Ok(parameter)
+}
+
+// Reads a parameter as its declared type.
+pub fn read_parameter(parameter: &Payload_Template_Parameter) -> Result<Parameter, SparkplugError> {
+    let data_type = parameter_type(parameter, "<unnamed>")?;
+    parameter_as(parameter, data_type)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TemplateDefinition {
+    name: String,
+    version: Option<String>,
+    members: Vec<Payload_Metric>,
+    parameters: Vec<Payload_Template_Parameter>,
+}
+
+impl TemplateDefinition {
+    pub fn new(name: &str) -> TemplateDefinition {
+        TemplateDefinition {
+            name: name.into(),
+            version: None,
+            members: vec![],
+            parameters: vec![],
+        }
+    }
+
+    pub fn with_version(mut self, version: &str) -> TemplateDefinition {
+        self.version = Some(version.into());
+        self
+    }
+
+    // A member metric, it must have a name and a datatype. Its value, if any,
+    // is the default of the member.
+    pub fn member(mut self, metric: Payload_Metric) -> TemplateDefinition {
+        self.members.push(metric);
+        self
+    }
+
+    pub fn parameter(mut self, parameter: Payload_Template_Parameter) -> TemplateDefinition {
+        self.parameters.push(parameter);
+        self
+    }
+
+    // Reads a definition published in an NBIRTH.
+    pub fn from_metric(metric: &Payload_Metric) -> Result<TemplateDefinition, SparkplugError> {
+        let template = metric.get_template_value();
+        if !metric.has_template_value() || !template.get_is_definition() {
+            return Err(SparkplugError::InvalidTemplate {
+                template: metric.get_name().into(),
+                reason: "not a template definition".into(),
+            });
+        }
+        Ok(TemplateDefinition {
+            name: metric.get_name().into(),
+            version: template
+                .has_version()
+                .then(|| template.get_version().into()),
+            members: template.get_metrics().to_vec(),
+            parameters: template.get_parameters().to_vec(),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn members(&self) -> &[Payload_Metric] {
+        &self.members
+    }
+
+    pub fn parameters(&self) -> &[Payload_Template_Parameter] {
+        &self.parameters
+    }
+
+    // The declared datatype of a member.
+    pub fn member_type(&self, name: &str) -> Option<MetricDataType> {
+        self.members
+            .iter()
+            .find(|m| m.get_name() == name)
+            .and_then(|m| MetricDataType::try_from(m.get_datatype()).ok())
+    }
+
+    // The declared datatype of a parameter.
+    pub fn parameter_type(&self, name: &str) -> Option<MetricDataType> {
+        self.parameters
+            .iter()
+            .find(|p| p.get_name() == name)
+            .and_then(|p| MetricDataType::try_from(p.get_field_type()).ok())
+    }
+
+    // The Template metric to publish in NBIRTH.
+    pub fn metric(&self, clock: &dyn Clock) -> Payload_Metric {
+        let mut metric = init_template_metric(clock, self.name.clone(), None, None);
+        fill_template(
+            metric.mut_template_value(),
+            &self.version,
+            &self.members,
+            &self.parameters,
+        );
+        metric
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TemplateInstance {
+    template_ref: String,
+    version: Option<String>,
+    members: Vec<Payload_Metric>,
+    parameters: Vec<Payload_Template_Parameter>,
+}
+
+impl TemplateInstance {
+    // An instance of the definition named `template_ref`.
+    pub fn new(template_ref: &str) -> TemplateInstance {
+        TemplateInstance {
+            template_ref: template_ref.into(),
+            version: None,
+            members: vec![],
+            parameters: vec![],
+        }
+    }
+
+    pub fn with_version(mut self, version: &str) -> TemplateInstance {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn member(mut self, metric: Payload_Metric) -> TemplateInstance {
+        self.members.push(metric);
+        self
+    }
+
+    // Overrides the parameter of the definition with the same name.
+    pub fn parameter(mut self, parameter: Payload_Template_Parameter) -> TemplateInstance {
+        self.parameters.push(parameter);
+        self
+    }
+
+    pub fn template_ref(&self) -> &str {
+        &self.template_ref
+    }
+
+    pub fn metric(&self, clock: &dyn Clock, name: String, alias: Option<u64>) -> Payload_Metric {
+        let mut metric = init_template_metric(clock, name, alias, Some(self.template_ref.clone()));
+        fill_template(
+            metric.mut_template_value(),
+            &self.version,
+            &self.members,
+            &self.parameters,
+        );
+        metric
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TemplateRegistry {
+    definitions: BTreeMap<String, TemplateDefinition>,
+}
+
+impl TemplateRegistry {
+    pub fn new() -> TemplateRegistry {
+        TemplateRegistry::default()
+    }
+
+    // Adds or replaces a definition. Members and parameters must be named,
+    // typed and unique, nested instances must refer to known definitions.
+    pub fn define(&mut self, definition: TemplateDefinition) -> Result<(), SparkplugError> {
+        let invalid = |reason: String| SparkplugError::InvalidTemplate {
+            template: definition.name.clone(),
+            reason,
+        };
+        let mut names = HashSet::new();
+        for member in &definition.members {
+            if !member.has_name() {
+                return Err(invalid("member without a name".into()));
+            }
+            if !names.insert(member.get_name()) {
+                return Err(invalid(format!(
+                    "member `{}` declared twice",
+                    member.get_name()
+                )));
+            }
+            let data_type = member_type(member)?;
+            if !member.get_is_null() {
+                decode_metric_as(member, data_type)?;
+            }
+            if data_type == MetricDataType::Template && member.has_template_value() {
+                self.validate(member.get_template_value())?;
+            }
+        }
+        let mut names = HashSet::new();
+        for parameter in &definition.parameters {
+            if !names.insert(parameter.get_name()) {
+                return Err(invalid(format!(
+                    "parameter `{}` declared twice",
+                    parameter.get_name()
+                )));
+            }
+            parameter_type(parameter, &definition.name)?;
+        }
+        self.definitions.insert(definition.name.clone(), definition);
+        Ok(())
+    }
+
+    // Defines every template definition among the metrics of a birth.
+    pub fn define_from_birth(&mut self, metrics: &[Payload_Metric]) -> Result<(), SparkplugError> {
+        for metric in metrics.iter().filter(|m| is_definition(m)) {
+            self.define(TemplateDefinition::from_metric(metric)?)?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TemplateDefinition> {
+        self.definitions.get(name)
+    }
+
+    pub fn definitions(&self) -> impl Iterator<Item = &TemplateDefinition> {
+        self.definitions.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+
+    // The definition metrics to publish in NBIRTH.
+    pub fn definition_metrics(&self, clock: &dyn Clock) -> Vec<Payload_Metric> {
+        self.definitions.values().map(|d| d.metric(clock)).collect()
+    }
+
+    // Checks a Template metric, other metrics always pass.
+    pub fn validate_metric(&self, metric: &Payload_Metric) -> Result<(), SparkplugError> {
+        if metric.get_is_null()
+            || !metric.has_template_value()
+            || metric.get_template_value().get_is_definition()
+        {
+            return Ok(());
+        }
+        self.validate(metric.get_template_value())
+    }
+
+    // Checks an instance against its definition: the version, and that every
+    // member and parameter is declared there with the same datatype.
+    pub fn validate(&self, instance: &Payload_Template) -> Result<(), SparkplugError> {
+        let template_ref = instance.get_template_ref();
+        let invalid = |reason: String| SparkplugError::InvalidTemplate {
+            template: template_ref.into(),
+            reason,
+        };
+        if instance.get_is_definition() {
+            return Err(invalid("a definition is not an instance".into()));
+        }
+        if !instance.has_template_ref() {
+            return Err(invalid("instance without a template_ref".into()));
+        }
+        let definition = self
+            .definitions
+            .get(template_ref)
+            .ok_or_else(|| SparkplugError::UnknownTemplate(template_ref.into()))?;
+        if let (Some(version), true) = (&definition.version, instance.has_version()) {
+            if version != instance.get_version() {
+                return Err(invalid(format!(
+                    "version {} but the definition is {}",
+                    instance.get_version(),
+                    version
+                )));
+            }
+        }
+        for member in instance.get_metrics() {
+            let data_type = definition.member_type(member.get_name()).ok_or_else(|| {
+                invalid(format!(
+                    "no member `{}` in the definition",
+                    member.get_name()
+                ))
+            })?;
+            if member.has_datatype() && member.get_datatype() != data_type as u32 {
+                return Err(SparkplugError::TypeMismatch {
+                    metric: member.get_name().into(),
+                    data_type,
+                    found: format!("datatype {}", member.get_datatype()),
+                });
+            }
+            if member.get_is_null() {
+                continue;
+            }
+            decode_metric_as(member, data_type)?;
+            if data_type == MetricDataType::Template {
+                self.validate(member.get_template_value())?;
+            }
+        }
+        for parameter in instance.get_parameters() {
+            let data_type = definition
+                .parameter_type(parameter.get_name())
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "no parameter `{}` in the definition",
+                        parameter.get_name()
+                    ))
+                })?;
+            if parameter.has_field_type() && parameter.get_field_type() != data_type as u32 {
+                return Err(SparkplugError::TypeMismatch {
+                    metric: parameter.get_name().into(),
+                    data_type,
+                    found: format!("datatype {}", parameter.get_field_type()),
+                });
+            }
+            check_parameter_value(parameter, data_type)?;
+        }
+        Ok(())
+    }
+
+    // Every parameter of the definition of `instance`, with the value the
+    // instance overrides it with, otherwise the default of the definition.
+    pub fn parameters(
+        &self,
+        instance: &Payload_Template,
+    ) -> Result<Vec<Parameter>, SparkplugError> {
+        self.validate(instance)?;
+        let definition = &self.definitions[instance.get_template_ref()];
+        definition
+            .parameters
+            .iter()
+            .map(|declared| {
+                let data_type = parameter_type(declared, &definition.name)?;
+                let parameter = instance
+                    .get_parameters()
+                    .iter()
+                    .find(|p| p.get_name() == declared.get_name())
+                    .unwrap_or(declared);
+                parameter_as(parameter, data_type)
+            })
+            .collect()
+    }
+
+    // One parameter of `instance`, resolved as by `parameters`.
+    pub fn parameter(
+        &self,
+        instance: &Payload_Template,
+        name: &str,
+    ) -> Result<Option<Parameter>, SparkplugError> {
+        Ok(self
+            .parameters(instance)?
+            .into_iter()
+            .find(|p| p.name == name))
+    }
+}
+
+pub(crate) fn is_definition(metric: &Payload_Metric) -> bool {
+    metric.has_template_value() && metric.get_template_value().get_is_definition()
+}
+
+pub(crate) fn parameter_raw(value: &Payload_Template_Parameter_oneof_value) -> Raw<'_> {
+    match value {
+        Payload_Template_Parameter_oneof_value::int_value(v) => Raw::Int(*v),
+        Payload_Template_Parameter_oneof_value::long_value(v) => Raw::Long(*v),
+        Payload_Template_Parameter_oneof_value::float_value(v) => Raw::Float(*v),
+        Payload_Template_Parameter_oneof_value::double_value(v) => Raw::Double(*v),
+        Payload_Template_Parameter_oneof_value::boolean_value(v) => Raw::Boolean(*v),
+        Payload_Template_Parameter_oneof_value::string_value(v) => Raw::String(v),
+        Payload_Template_Parameter_oneof_value::extension_value(_) => Raw::Extension,
+    }
+}
+
+fn member_type(member: &Payload_Metric) -> Result<MetricDataType, SparkplugError> {
+    if !member.has_datatype() {
+        return Err(SparkplugError::MissingDataType {
+            metric: member.get_name().into(),
+        });
+    }
+    MetricDataType::try_from(member.get_datatype()).map_err(|_| SparkplugError::UnknownDataType {
+        metric: member.get_name().into(),
+        datatype: member.get_datatype(),
+    })
+}
+
+fn parameter_type(
+    parameter: &Payload_Template_Parameter,
+    template: &str,
+) -> Result<MetricDataType, SparkplugError> {
+    if !parameter.has_name() {
+        return Err(SparkplugError::InvalidTemplate {
+            template: template.into(),
+            reason: "parameter without a name".into(),
+        });
+    }
+    if !parameter.has_field_type() {
+        return Err(SparkplugError::MissingDataType {
+            metric: parameter.get_name().into(),
+        });
+    }
+    let data_type = MetricDataType::try_from(parameter.get_field_type()).map_err(|_| {
+        SparkplugError::UnknownDataType {
+            metric: parameter.get_name().into(),
+            datatype: parameter.get_field_type(),
+        }
+    })?;
+    check_parameter_value(parameter, data_type)?;
+    Ok(data_type)
+}
+
+// A parameter without a value is allowed, it has no default.
+fn check_parameter_value(
+    parameter: &Payload_Template_Parameter,
+    data_type: MetricDataType,
+) -> Result<(), SparkplugError> {
+    parameter_as(parameter, data_type).map(|_| ())
+}
+
+fn parameter_as(
+    parameter: &Payload_Template_Parameter,
+    data_type: MetricDataType,
+) -> Result<Parameter, SparkplugError> {
+    let value = match &parameter.value {
+        None => None,
+        Some(value) => {
+            let raw = parameter_raw(value);
+            let value =
+                scalar_value(data_type, &raw).map_err(|_| SparkplugError::TypeMismatch {
+                    metric: parameter.get_name().into(),
+                    data_type,
+                    found: raw.field().into(),
+                })?;
+            Some(value)
+        }
+    };
+    Ok(Parameter {
+        name: parameter.get_name().into(),
+        data_type,
+        value,
+    })
+}
+
+fn fill_template(
+    template: &mut Payload_Template,
+    version: &Option<String>,
+    members: &[Payload_Metric],
+    parameters: &[Payload_Template_Parameter],
+) {
+    if let Some(version) = version {
+        template.set_version(version.clone());
+    }
+    template.set_metrics(RepeatedField::from_slice(members));
+    template.set_parameters(RepeatedField::from_slice(parameters));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_metric, FixedClock};
+
+    fn clock() -> FixedClock {
+        FixedClock::new(1000)
+    }
+
+    fn member(name: &str, data_type: MetricDataType, value: MetricValue) -> Payload_Metric {
+        create_metric(&clock(), data_type, value, name.into(), None, None).unwrap()
+    }
+
+    fn motor() -> TemplateDefinition {
+        TemplateDefinition::new("Motor")
+            .with_version("1.0")
+            .member(member("RPM", MetricDataType::UInt32, 0u32.into()))
+            .member(member("Running", MetricDataType::Boolean, false.into()))
+    }
+
+    #[test]
+    fn definitions_round_trip_through_their_metric() {
+        let mut registry = TemplateRegistry::new();
+        assert!(registry.is_empty());
+        registry.define(motor()).unwrap();
+        let definition = registry.get("Motor").unwrap();
+        assert_eq!(definition.version(), Some("1.0"));
+        assert_eq!(definition.member_type("RPM"), Some(MetricDataType::UInt32));
+        assert_eq!(definition.member_type("Speed"), None);
+
+        let metrics = registry.definition_metrics(&clock());
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].get_name(), "Motor");
+        assert!(is_definition(&metrics[0]));
+        assert_eq!(
+            &TemplateDefinition::from_metric(&metrics[0]).unwrap(),
+            definition
+        );
+
+        let mut from_birth = TemplateRegistry::new();
+        let other = member("Temperature", MetricDataType::Float, 1.5f32.into());
+        from_birth
+            .define_from_birth(&[other, metrics[0].clone()])
+            .unwrap();
+        assert_eq!(from_birth.get("Motor"), Some(definition));
+        assert_eq!(from_birth.definitions().count(), 1);
+    }
+
+    #[test]
+    fn definition_members_must_be_named_typed_and_unique() {
+        let mut registry = TemplateRegistry::new();
+        let twice = motor().member(member("RPM", MetricDataType::UInt32, 1u32.into()));
+        assert!(matches!(
+            registry.define(twice),
+            Err(SparkplugError::InvalidTemplate { reason, .. })
+                if reason == "member `RPM` declared twice"
+        ));
+        let mut untyped = member("Load", MetricDataType::Float, 1.0f32.into());
+        untyped.clear_datatype();
+        assert!(matches!(
+            registry.define(motor().member(untyped)),
+            Err(SparkplugError::MissingDataType { metric }) if metric == "Load"
+        ));
+        let mut unnamed = member("Load", MetricDataType::Float, 1.0f32.into());
+        unnamed.clear_name();
+        assert!(matches!(
+            registry.define(motor().member(unnamed)),
+            Err(SparkplugError::InvalidTemplate { .. })
+        ));
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn instances_are_checked_against_their_definition() {
+        let mut registry = TemplateRegistry::new();
+        registry.define(motor()).unwrap();
+        let instance = TemplateInstance::new("Motor")
+            .with_version("1.0")
+            .member(member("RPM", MetricDataType::UInt32, 1500u32.into()))
+            .metric(&clock(), "Motor 1".into(), None);
+        registry.validate_metric(&instance).unwrap();
+
+        let unknown_member = TemplateInstance::new("Motor")
+            .member(member("Speed", MetricDataType::UInt32, 1u32.into()))
+            .metric(&clock(), "Motor 1".into(), None);
+        assert!(matches!(
+            registry.validate_metric(&unknown_member),
+            Err(SparkplugError::InvalidTemplate { reason, .. })
+                if reason == "no member `Speed` in the definition"
+        ));
+        let wrong_type = TemplateInstance::new("Motor")
+            .member(member("RPM", MetricDataType::Double, 1.0f64.into()))
+            .metric(&clock(), "Motor 1".into(), None);
+        assert!(matches!(
+            registry.validate_metric(&wrong_type),
+            Err(SparkplugError::TypeMismatch { metric, .. }) if metric == "RPM"
+        ));
+        let wrong_version = TemplateInstance::new("Motor").with_version("2.0").metric(
+            &clock(),
+            "Motor 1".into(),
+            None,
+        );
+        assert!(matches!(
+            registry.validate_metric(&wrong_version),
+            Err(SparkplugError::InvalidTemplate { .. })
+        ));
+    }
+
+    #[test]
+    fn instance_of_unknown_definition_is_rejected() {
+        let mut registry = TemplateRegistry::new();
+        registry.define(motor()).unwrap();
+        let instance = TemplateInstance::new("Pump").metric(&clock(), "Pump 1".into(), None);
+        assert!(matches!(
+            registry.validate_metric(&instance),
+            Err(SparkplugError::UnknownTemplate(t)) if t == "Pump"
+        ));
+        let nested = TemplateDefinition::new("Line").member(instance);
+        assert!(matches!(
+            registry.define(nested),
+            Err(SparkplugError::UnknownTemplate(t)) if t == "Pump"
+        ));
+        let plain = member("RPM", MetricDataType::UInt32, 1u32.into());
+        registry.validate_metric(&plain).unwrap();
+    }
+
+    fn pump() -> TemplateDefinition {
+        TemplateDefinition::new("Pump")
+            .member(member("Flow", MetricDataType::Double, 0.0f64.into()))
+            .parameter(create_parameter("MaxFlow", MetricDataType::Double, 12.5f64).unwrap())
+            .parameter(create_parameter("Unit", MetricDataType::String, "l/min").unwrap())
+            .parameter(declare_parameter("Serial", MetricDataType::UInt32).unwrap())
+    }
+
+    #[test]
+    fn parameters_are_typed() {
+        let parameter = create_parameter("Limit", MetricDataType::Int16, -3i32).unwrap();
+        let read = read_parameter(&parameter).unwrap();
+        assert_eq!(read.data_type, MetricDataType::Int16);
+        assert_eq!(read.value, Some(MetricValue::Int16(-3)));
+        assert_eq!(read.get::<i16>(), Some(-3));
+        assert_eq!(read.get::<String>(), None);
+
+        assert!(matches!(
+            create_parameter("Limit", MetricDataType::UInt8, 300u32),
+            Err(SparkplugError::TypeMismatch { metric, found, .. })
+                if metric == "Limit" && found == "UInt32"
+        ));
+        assert!(matches!(
+            create_parameter("Limit", MetricDataType::Int32, "3"),
+            Err(SparkplugError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            declare_parameter("Raw", MetricDataType::Bytes),
+            Err(SparkplugError::UnsupportedType { .. })
+        ));
+
+        let mut untyped = Payload_Template_Parameter::new();
+        untyped.set_name("Limit".into());
+        untyped.set_int_value(3);
+        assert!(matches!(
+            read_parameter(&untyped),
+            Err(SparkplugError::MissingDataType { metric }) if metric == "Limit"
+        ));
+        untyped.set_field_type(MetricDataType::String as u32);
+        assert!(matches!(
+            read_parameter(&untyped),
+            Err(SparkplugError::TypeMismatch { found, .. }) if found == "int_value"
+        ));
+    }
+
+    #[test]
+    fn instances_override_definition_defaults() {
+        let mut registry = TemplateRegistry::new();
+        registry.define(pump()).unwrap();
+        let instance = TemplateInstance::new("Pump")
+            .parameter(create_parameter("MaxFlow", MetricDataType::Double, 20.0f64).unwrap())
+            .metric(&clock(), "Pump 1".into(), None);
+        let parameters = registry.parameters(instance.get_template_value()).unwrap();
+        let values: Vec<_> = parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.value.clone()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                ("MaxFlow", Some(MetricValue::Double(20.0))),
+                ("Unit", Some(MetricValue::String("l/min".into()))),
+                ("Serial", None),
+            ]
+        );
+        let unit = registry
+            .parameter(instance.get_template_value(), "Unit")
+            .unwrap();
+        assert_eq!(unit.unwrap().get::<String>().as_deref(), Some("l/min"));
+        assert_eq!(
+            registry
+                .parameter(instance.get_template_value(), "Other")
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn instance_parameters_must_match_the_definition() {
+        let mut registry = TemplateRegistry::new();
+        registry.define(pump()).unwrap();
+        let retyped = TemplateInstance::new("Pump")
+            .parameter(create_parameter("MaxFlow", MetricDataType::Float, 20.0f32).unwrap())
+            .metric(&clock(), "Pump 1".into(), None);
+        assert!(matches!(
+            registry.validate_metric(&retyped),
+            Err(SparkplugError::TypeMismatch { metric, data_type: MetricDataType::Double, .. })
+                if metric == "MaxFlow"
+        ));
+        let undeclared = TemplateInstance::new("Pump")
+            .parameter(create_parameter("MinFlow", MetricDataType::Double, 1.0f64).unwrap())
+            .metric(&clock(), "Pump 1".into(), None);
+        assert!(matches!(
+            registry.validate_metric(&undeclared),
+            Err(SparkplugError::InvalidTemplate { reason, .. })
+                if reason == "no parameter `MinFlow` in the definition"
+        ));
+        let twice = pump().parameter(declare_parameter("Unit", MetricDataType::String).unwrap());
+        assert!(matches!(
+            registry.define(twice),
+            Err(SparkplugError::InvalidTemplate { reason, .. })
+                if reason == "parameter `Unit` declared twice"
+        ));
+    }
+}
@@ -0,0 +1,376 @@
+use crate::decode::{decode_payload, metric_label, Metric};
+use crate::host::REBIRTH_METRIC;
+use crate::sparkplug_b::{Payload, Payload_Metric};
+use crate::{MessageType, MetricDataType, MetricValue, Topic};
+use std::collections::HashSet;
+
+// ######################################################################
+// # Payload validation
+// ######################################################################
+// Checks a single message against the Sparkplug B 3.0 rules that can be told
+// from its topic and payload alone:
+//
+//   - every metric value matches its datatype, see `decode`
+//   - births declare a name and datatype for every metric, names and aliases
+//     are unique
+//   - other messages name each metric or give its alias
+//   - NBIRTH has seq 0, a bdSeq and a `Node Control/Rebirth` metric
+//   - NDEATH has a bdSeq and no seq
+//   - births, data and DDEATH carry a seq between 0 and 255
+//   - every message except NDEATH carries a timestamp
+//   - template definitions only appear in NBIRTH
+//
+// Rules that need the session, such as seq following the previous message
+// or DATA only using metrics from the birth, are checked by `host`.
+// ######################################################################
+
+// The rules the message breaks, empty when it is valid.
+pub fn validate(topic: &Topic, payload: &Payload) -> Vec<String> {
+    let message_type = topic.message_type();
+    if message_type == MessageType::STATE {
+        return vec!["STATE messages are not protobuf payloads".into()];
+    }
+    let mut problems = vec![];
+    let birth = matches!(message_type, MessageType::NBIRTH | MessageType::DBIRTH);
+
+    if message_type != MessageType::NDEATH && !payload.has_timestamp() {
+        problems.push(format!("{:?} without a timestamp", message_type));
+    }
+    match message_type {
+        MessageType::NDEATH if payload.has_seq() => {
+            problems.push("NDEATH must not carry a seq".into())
+        }
+        MessageType::NBIRTH if payload.get_seq() != 0 || !payload.has_seq() => {
+            problems.push("NBIRTH must carry seq 0".into())
+        }
+        MessageType::DBIRTH | MessageType::NDATA | MessageType::DDATA | MessageType::DDEATH => {
+            if !payload.has_seq() {
+                problems.push(format!("{:?} without a seq", message_type));
+            } else if payload.get_seq() > 255 {
+                problems.push(format!("seq {} is above 255", payload.get_seq()));
+            }
+        }
+        _ => (),
+    }
+
+    let mut names = HashSet::new();
+    let mut aliases = HashSet::new();
+    for metric in payload.get_metrics() {
+        problems.extend(check_metric(metric, message_type, birth));
+        if birth && metric.has_name() && !names.insert(metric.get_name()) {
+            problems.push(format!("metric `{}` appears twice", metric.get_name()));
+        }
+        if birth && metric.has_alias() && !aliases.insert(metric.get_alias()) {
+            problems.push(format!(
+                "metric `{}`: alias {} is already used",
+                metric_label(metric),
+                metric.get_alias()
+            ));
+        }
+    }
+
+    match decode_payload(topic.clone(), payload) {
+        Err(e) => problems.push(e.to_string()),
+        Ok(message) => {
+            if matches!(message_type, MessageType::NBIRTH | MessageType::NDEATH)
+                && !has_bd_seq(&message.metrics)
+            {
+                problems.push(format!(
+                    "{:?} without an Int64 or UInt64 bdSeq metric",
+                    message_type
+                ));
+            }
+            if message_type == MessageType::NBIRTH && !has_rebirth(&message.metrics) {
+                problems.push("NBIRTH without a Boolean `Node Control/Rebirth` metric".into());
+            }
+        }
+    }
+    problems
+}
+
+fn check_metric(metric: &Payload_Metric, message_type: MessageType, birth: bool) -> Vec<String> {
+    let mut problems = vec![];
+    if birth && !metric.has_name() {
+        problems.push(format!(
+            "{:?} metric {} without a name",
+            message_type,
+            metric_label(metric)
+        ));
+    }
+    if !metric.has_name() && !metric.has_alias() {
+        problems.push("metric without a name or an alias".into());
+    }
+    let definition = metric.has_template_value() && metric.get_template_value().get_is_definition();
+    if definition && message_type != MessageType::NBIRTH {
+        problems.push(format!(
+            "template definition `{}` outside of NBIRTH",
+            metric_label(metric)
+        ));
+    }
+    problems
+}
+
+fn has_bd_seq(metrics: &[Metric]) -> bool {
+    metrics.iter().any(|m| {
+        m.name.as_deref() == Some("bdSeq")
+            && matches!(m.data_type, MetricDataType::Int64 | MetricDataType::UInt64)
+    })
+}
+
+fn has_rebirth(metrics: &[Metric]) -> bool {
+    metrics.iter().any(|m| {
+        m.name.as_deref() == Some(REBIRTH_METRIC)
+            && matches!(m.value, Some(MetricValue::Boolean(_)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::TemplateDefinition;
+    use crate::{create_metric, FixedClock};
+    use protobuf::RepeatedField;
+
+    fn node(message_type: MessageType) -> Topic {
+        Topic::node("group", message_type, "node").unwrap()
+    }
+
+    fn device(message_type: MessageType) -> Topic {
+        Topic::device("group", message_type, "node", "device").unwrap()
+    }
+
+    fn metric<V: Into<MetricValue>>(
+        data_type: MetricDataType,
+        value: V,
+        name: &str,
+        alias: Option<u64>,
+    ) -> Payload_Metric {
+        let clock = FixedClock::new(1000);
+        create_metric(&clock, data_type, value, name.into(), alias, None).unwrap()
+    }
+
+    fn payload(seq: Option<u64>, metrics: Vec<Payload_Metric>) -> Payload {
+        let mut payload = Payload::new();
+        payload.set_timestamp(1000);
+        if let Some(seq) = seq {
+            payload.set_seq(seq);
+        }
+        payload.set_metrics(RepeatedField::from_vec(metrics));
+        payload
+    }
+
+    fn bd_seq() -> Payload_Metric {
+        metric(MetricDataType::Int64, 3i64, "bdSeq", None)
+    }
+
+    fn rebirth() -> Payload_Metric {
+        metric(MetricDataType::Boolean, false, "Node Control/Rebirth", None)
+    }
+
+    fn temperature() -> Payload_Metric {
+        metric(MetricDataType::Double, 21.5, "Temperature", Some(1))
+    }
+
+    fn nbirth(metrics: Vec<Payload_Metric>) -> Payload {
+        payload(Some(0), metrics)
+    }
+
+    fn valid_nbirth() -> Payload {
+        nbirth(vec![bd_seq(), rebirth(), temperature()])
+    }
+
+    #[test]
+    fn valid_messages_pass() {
+        let none = Vec::<String>::new();
+        assert_eq!(validate(&node(MessageType::NBIRTH), &valid_nbirth()), none);
+        let mut death = payload(None, vec![bd_seq()]);
+        death.clear_timestamp();
+        assert_eq!(validate(&node(MessageType::NDEATH), &death), none);
+        let data = payload(Some(1), vec![temperature()]);
+        assert_eq!(validate(&node(MessageType::NDATA), &data), none);
+        let birth = payload(Some(2), vec![temperature()]);
+        assert_eq!(validate(&device(MessageType::DBIRTH), &birth), none);
+        assert_eq!(validate(&device(MessageType::DDATA), &data), none);
+        assert_eq!(
+            validate(&device(MessageType::DDEATH), &payload(Some(3), vec![])),
+            none
+        );
+    }
+
+    #[test]
+    fn nbirth_needs_a_bd_seq() {
+        let birth = nbirth(vec![rebirth(), temperature()]);
+        assert_eq!(
+            validate(&node(MessageType::NBIRTH), &birth),
+            ["NBIRTH without an Int64 or UInt64 bdSeq metric"]
+        );
+        let text_bd_seq = metric(MetricDataType::String, "3", "bdSeq", None);
+        let birth = nbirth(vec![text_bd_seq, rebirth()]);
+        assert_eq!(
+            validate(&node(MessageType::NBIRTH), &birth),
+            ["NBIRTH without an Int64 or UInt64 bdSeq metric"]
+        );
+        let unsigned = metric(MetricDataType::UInt64, 3u64, "bdSeq", None);
+        let birth = nbirth(vec![unsigned, rebirth()]);
+        assert!(validate(&node(MessageType::NBIRTH), &birth).is_empty());
+    }
+
+    #[test]
+    fn ndeath_needs_a_bd_seq_and_no_seq() {
+        assert_eq!(
+            validate(&node(MessageType::NDEATH), &payload(None, vec![])),
+            ["NDEATH without an Int64 or UInt64 bdSeq metric"]
+        );
+        assert_eq!(
+            validate(
+                &node(MessageType::NDEATH),
+                &payload(Some(0), vec![bd_seq()])
+            ),
+            ["NDEATH must not carry a seq"]
+        );
+    }
+
+    #[test]
+    fn nbirth_needs_the_rebirth_metric() {
+        assert_eq!(
+            validate(&node(MessageType::NBIRTH), &nbirth(vec![bd_seq()])),
+            ["NBIRTH without a Boolean `Node Control/Rebirth` metric"]
+        );
+        let int_rebirth = metric(MetricDataType::Int32, 0i32, "Node Control/Rebirth", None);
+        assert_eq!(
+            validate(
+                &node(MessageType::NBIRTH),
+                &nbirth(vec![bd_seq(), int_rebirth])
+            ),
+            ["NBIRTH without a Boolean `Node Control/Rebirth` metric"]
+        );
+    }
+
+    #[test]
+    fn nbirth_has_seq_0() {
+        let mut birth = valid_nbirth();
+        birth.set_seq(1);
+        assert_eq!(
+            validate(&node(MessageType::NBIRTH), &birth),
+            ["NBIRTH must carry seq 0"]
+        );
+        birth.clear_seq();
+        assert_eq!(
+            validate(&node(MessageType::NBIRTH), &birth),
+            ["NBIRTH must carry seq 0"]
+        );
+    }
+
+    #[test]
+    fn other_messages_carry_a_seq_up_to_255() {
+        for topic in [
+            node(MessageType::NDATA),
+            device(MessageType::DBIRTH),
+            device(MessageType::DDATA),
+            device(MessageType::DDEATH),
+        ] {
+            assert!(validate(&topic, &payload(Some(255), vec![])).is_empty());
+            assert_eq!(
+                validate(&topic, &payload(Some(256), vec![])),
+                ["seq 256 is above 255"]
+            );
+            assert_eq!(
+                validate(&topic, &payload(None, vec![])),
+                [format!("{:?} without a seq", topic.message_type())]
+            );
+        }
+    }
+
+    #[test]
+    fn messages_carry_a_timestamp() {
+        let mut data = payload(Some(1), vec![temperature()]);
+        data.clear_timestamp();
+        assert_eq!(
+            validate(&node(MessageType::NDATA), &data),
+            ["NDATA without a timestamp"]
+        );
+    }
+
+    #[test]
+    fn birth_metrics_are_named_typed_and_unique() {
+        let mut unnamed = temperature();
+        unnamed.clear_name();
+        assert_eq!(
+            validate(
+                &node(MessageType::NBIRTH),
+                &nbirth(vec![bd_seq(), rebirth(), unnamed])
+            ),
+            ["NBIRTH metric alias 1 without a name"]
+        );
+        let mut untyped = temperature();
+        untyped.clear_datatype();
+        assert_eq!(
+            validate(
+                &node(MessageType::NBIRTH),
+                &nbirth(vec![bd_seq(), rebirth(), untyped])
+            ),
+            ["metric `Temperature`: no datatype"]
+        );
+        let twice = nbirth(vec![bd_seq(), rebirth(), temperature(), temperature()]);
+        assert_eq!(
+            validate(&node(MessageType::NBIRTH), &twice),
+            [
+                "metric `Temperature` appears twice",
+                "metric `Temperature`: alias 1 is already used"
+            ]
+        );
+        // Data messages may repeat a metric, e.g. with historical values.
+        let data = payload(Some(1), vec![temperature(), temperature()]);
+        assert!(validate(&node(MessageType::NDATA), &data).is_empty());
+    }
+
+    #[test]
+    fn data_metrics_need_a_name_or_an_alias() {
+        let mut alias_only = temperature();
+        alias_only.clear_name();
+        alias_only.clear_datatype();
+        let data = payload(Some(1), vec![alias_only.clone()]);
+        assert!(validate(&node(MessageType::NDATA), &data).is_empty());
+        alias_only.clear_alias();
+        let data = payload(Some(1), vec![alias_only]);
+        assert_eq!(
+            validate(&node(MessageType::NDATA), &data),
+            ["metric without a name or an alias"]
+        );
+    }
+
+    #[test]
+    fn values_match_their_datatype() {
+        let mut wrong = temperature();
+        wrong.set_int_value(1);
+        let data = payload(Some(1), vec![wrong]);
+        assert_eq!(
+            validate(&node(MessageType::NDATA), &data),
+            ["metric `Temperature`: declared Double but got int_value"]
+        );
+    }
+
+    #[test]
+    fn template_definitions_only_in_nbirth() {
+        let clock = FixedClock::new(1000);
+        let definition = TemplateDefinition::new("Motor")
+            .member(metric(MetricDataType::Int32, 0i32, "rpm", None))
+            .metric(&clock);
+        let birth = nbirth(vec![bd_seq(), rebirth(), definition.clone()]);
+        assert!(validate(&node(MessageType::NBIRTH), &birth).is_empty());
+        let birth = payload(Some(1), vec![definition]);
+        assert_eq!(
+            validate(&device(MessageType::DBIRTH), &birth),
+            ["template definition `Motor` outside of NBIRTH"]
+        );
+    }
+
+    #[test]
+    fn state_is_not_a_payload() {
+        let topic = Topic::state("host").unwrap();
+        assert_eq!(
+            validate(&topic, &Payload::new()),
+            ["STATE messages are not protobuf payloads"]
+        );
+    }
+}